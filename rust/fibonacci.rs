@@ -3,6 +3,32 @@
 
 use std::time::Instant;
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+// Errors reported by the checked Fibonacci functions
+#[derive(Debug, Clone, PartialEq)]
+enum FibError {
+    Overflow { at: u32 },
+    DepthExceeded { max_depth: usize },
+}
+
+impl fmt::Display for FibError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FibError::Overflow { at } => {
+                write!(f, "fib({}) overflows u64", at)
+            }
+            FibError::DepthExceeded { max_depth } => {
+                write!(f, "recursion deeper than {} frames", max_depth)
+            }
+        }
+    }
+}
+
+impl Error for FibError {}
 
 // 1. Naive recursive - exponential time
 fn fib_recursive_naive(n: u32, depth: usize) -> u64 {
@@ -20,6 +46,60 @@ fn fib_recursive_naive(n: u32, depth: usize) -> u64 {
     result
 }
 
+// Naive recursion with a frame budget - fails cleanly instead of
+// overflowing the real call stack. fib(n) needs n frames (1 for n = 0).
+fn fib_recursive_bounded(n: u32, max_depth: usize) -> Result<u64, FibError> {
+    fib_bounded_helper(n, 1, max_depth)
+}
+
+fn fib_bounded_helper(n: u32, depth: usize, max_depth: usize) -> Result<u64, FibError> {
+    if depth > max_depth {
+        return Err(FibError::DepthExceeded { max_depth });
+    }
+    if n <= 1 {
+        return Ok(n as u64);
+    }
+    Ok(fib_bounded_helper(n - 1, depth + 1, max_depth)? +
+       fib_bounded_helper(n - 2, depth + 1, max_depth)?)
+}
+
+// Naive recursion that records the call tree as data instead of printing.
+// The tracer mirrors the real call stack: enter pushes n, exit pops it.
+struct CallTracer {
+    stack: Vec<u32>,
+    events: Vec<(u32, bool)>,  // (n, true) = enter, (n, false) = exit
+}
+
+impl CallTracer {
+    fn enter(&mut self, n: u32) {
+        self.stack.push(n);
+        self.events.push((n, true));
+    }
+    
+    fn exit(&mut self) {
+        if let Some(n) = self.stack.pop() {
+            self.events.push((n, false));
+        }
+    }
+}
+
+fn fib_traced(n: u32) -> (u64, Vec<(u32, bool)>) {
+    let mut tracer = CallTracer { stack: Vec::new(), events: Vec::new() };
+    let result = fib_traced_helper(n, &mut tracer);
+    (result, tracer.events)
+}
+
+fn fib_traced_helper(n: u32, tracer: &mut CallTracer) -> u64 {
+    tracer.enter(n);
+    let result = if n <= 1 {
+        n as u64
+    } else {
+        fib_traced_helper(n - 1, tracer) + fib_traced_helper(n - 2, tracer)
+    };
+    tracer.exit();
+    result
+}
+
 // 2. Iterative - linear time, constant space
 fn fib_iterative(n: u32) -> u64 {
     if n <= 1 {
@@ -40,6 +120,23 @@ fn fib_iterative(n: u32) -> u64 {
     prev1
 }
 
+// Iterative, but keeping every value: F(0)..=F(n) in one allocation.
+// Fails for n >= 94, where the last value no longer fits in u64.
+fn fib_sequence(n: u32) -> Result<Vec<u64>, FibError> {
+    let mut sequence: Vec<u64> = Vec::with_capacity(n as usize + 1);
+    sequence.push(0);
+    if n >= 1 {
+        sequence.push(1);
+    }
+    for i in 2..=n {
+        let len = sequence.len();
+        let current = sequence[len - 1].checked_add(sequence[len - 2])
+            .ok_or(FibError::Overflow { at: i })?;
+        sequence.push(current);
+    }
+    Ok(sequence)
+}
+
 // 3. Memoized with HashMap - dynamic programming
 fn fib_memoized_helper(n: u32, memo: &mut HashMap<u32, u64>, depth: usize) -> u64 {
     let indent = "  ".repeat(depth);
@@ -94,6 +191,19 @@ const fn fib_const(n: u32) -> u64 {
 // Compile-time constant!
 const FIB_10: u64 = fib_const(10);
 
+// Const generics + const fn: a whole table F(0)..F(N-1) built at compile time
+const fn fib_array<const N: usize>() -> [u64; N] {
+    let mut table = [0u64; N];
+    let mut i = 1;
+    while i < N {
+        table[i] = if i == 1 { 1 } else { table[i - 1] + table[i - 2] };
+        i += 1;
+    }
+    table
+}
+
+const FIBS: [u64; 11] = fib_array::<11>();
+
 // 5. Iterator-based approach (lazy evaluation)
 struct FibonacciIterator {
     prev: u64,
@@ -116,6 +226,236 @@ impl Iterator for FibonacciIterator {
         self.curr = next;
         Some(result)
     }
+    
+    // O(log n) skip instead of n calls to next(). With prev = F(k) and
+    // curr = F(k+1): F(k+n) = F(k)·F(n-1) + F(k+1)·F(n).
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (fn0, fn1) = fib_pair(n as u64);  // F(n), F(n+1)
+        let result = self.prev * (fn1 - fn0) + self.curr * fn0;
+        let following = self.prev * fn0 + self.curr * fn1;
+        self.prev = following;
+        self.curr = result + following;
+        Some(result)
+    }
+}
+
+// Fast doubling: (F(n), F(n+1)) from (F(n/2), F(n/2+1)) in O(log n)
+//   F(2k)   = F(k) · (2F(k+1) - F(k))
+//   F(2k+1) = F(k)² + F(k+1)²
+fn fib_pair(n: u64) -> (u64, u64) {
+    if n == 0 {
+        return (0, 1);
+    }
+    let (a, b) = fib_pair(n / 2);
+    let even = a * (2 * b - a);
+    let odd = a * a + b * b;
+    if n.is_multiple_of(2) {
+        (even, odd)
+    } else {
+        (odd, even + odd)
+    }
+}
+
+// 6. Checked iterative - reports overflow instead of panicking
+fn fib_checked(n: u32) -> Result<u64, FibError> {
+    if n <= 1 {
+        return Ok(n as u64);
+    }
+    
+    let (mut prev2, mut prev1) = (0u64, 1u64);
+    for i in 2..=n {
+        let current = prev1.checked_add(prev2)
+            .ok_or(FibError::Overflow { at: i })?;
+        prev2 = prev1;
+        prev1 = current;
+    }
+    
+    Ok(prev1)
+}
+
+// 7. Process-wide cache - shared by every caller, initialized lazily.
+// Overflow is reported, not panicked on: a panic while holding the lock
+// would poison the cache for every other caller.
+static FIB_CACHE: OnceLock<Mutex<HashMap<u32, u64>>> = OnceLock::new();
+
+fn fib_cached(n: u32) -> Result<u64, FibError> {
+    let mut cache = FIB_CACHE
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap();
+    
+    if let Some(&result) = cache.get(&n) {
+        return Ok(result);
+    }
+    
+    cache.insert(0, 0);
+    cache.insert(1, 1);
+    for i in 2..=n {
+        if !cache.contains_key(&i) {
+            let current = cache[&(i - 1)].checked_add(cache[&(i - 2)])
+                .ok_or(FibError::Overflow { at: i })?;
+            cache.insert(i, current);
+        }
+    }
+    Ok(cache[&n])
+}
+
+// 8. Ratio table - F(i) / F(i-1) converges to the golden ratio φ.
+// Returns n rows starting at index 2, since F(1) / F(0) divides by zero.
+// Rows stop at F(93), so at most 92 are returned.
+fn fib_ratio_table(n: u32) -> Vec<(u32, u64, f64)> {
+    fibs_in_u64()
+        .zip(fibs_in_u64().skip(1))
+        .skip(1)
+        .take(n as usize)
+        .enumerate()
+        .map(|(i, (prev, curr))| (i as u32 + 2, curr, curr as f64 / prev as f64))
+        .collect()
+}
+
+// Binet's formula: F(n) = round(φ^n / √5). Exact only while f64's 53-bit
+// mantissa keeps the rounding error under 0.5 - roughly n ≤ 70. With powi
+// on x86-64 it happens to hold through n = 75 and first fails at 76.
+fn fib_closed_form(n: u32) -> u64 {
+    let sqrt5 = 5f64.sqrt();
+    let phi = (1.0 + sqrt5) / 2.0;
+    (phi.powi(n as i32) / sqrt5).round() as u64
+}
+
+// Runs the recurrence backwards: F(n-1) = F(n+1) - F(n).
+// Yields F(n), F(n-1), ..., F(0), then stops.
+struct BackwardFibonacci {
+    curr: u64,       // F(k)
+    prev: u64,       // F(k-1)
+    remaining: u32,  // values left to yield, including curr
+}
+
+impl BackwardFibonacci {
+    // Fails for n >= 94, where F(n) doesn't fit in u64
+    fn starting_at(n: u32) -> Result<Self, FibError> {
+        // Walk forward once to find F(n) and F(n-1)
+        let (mut prev, mut curr) = (1u64, 0u64);  // F(-1) = 1, F(0) = 0
+        for i in 1..=n {
+            let next = prev.checked_add(curr).ok_or(FibError::Overflow { at: i })?;
+            prev = curr;
+            curr = next;
+        }
+        Ok(BackwardFibonacci { curr, prev, remaining: n + 1 })
+    }
+}
+
+impl Iterator for BackwardFibonacci {
+    type Item = u64;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let result = self.curr;
+        if self.remaining > 0 {
+            let older = self.curr - self.prev;
+            self.curr = self.prev;
+            self.prev = older;
+        }
+        Some(result)
+    }
+}
+
+// Pisano period: F(n) mod m repeats, always restarting at 0, 1.
+// Returns one full period. m must be at least 1.
+fn pisano_sequence(m: u64) -> Vec<u64> {
+    assert!(m > 0, "modulus must be at least 1");
+    let mut sequence = vec![0];
+    let (mut a, mut b) = (0u64, 1 % m);
+    
+    loop {
+        let next = ((a as u128 + b as u128) % m as u128) as u64;
+        a = b;
+        b = next;
+        if a == 0 && b == 1 % m {
+            break;
+        }
+        sequence.push(a);
+    }
+    
+    sequence
+}
+
+fn pisano_period(m: u64) -> usize {
+    pisano_sequence(m).len()
+}
+
+// F(n) mod m in O(log n): raises [[1, 1], [1, 0]] to the n-th power by
+// squaring, reducing every entry mod m. Products go through u128, so any
+// u64 modulus is safe.
+fn fib_mod_matrix(n: u64, m: u64) -> u64 {
+    assert!(m > 0, "modulus must be at least 1");
+    type Matrix = [[u64; 2]; 2];
+    
+    let multiply = |a: &Matrix, b: &Matrix| -> Matrix {
+        let mut product = [[0u64; 2]; 2];
+        for (i, row) in product.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                let sum = a[i][0] as u128 * b[0][j] as u128
+                        + a[i][1] as u128 * b[1][j] as u128;
+                *cell = (sum % m as u128) as u64;
+            }
+        }
+        product
+    };
+    
+    let mut result: Matrix = [[1 % m, 0], [0, 1 % m]];
+    let mut base: Matrix = [[1 % m, 1 % m], [1 % m, 0]];
+    let mut exponent = n;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        base = multiply(&base, &base);
+        exponent >>= 1;
+    }
+    
+    // [[F(n+1), F(n)], [F(n), F(n-1)]]
+    result[0][1]
+}
+
+// F(0), F(1), ... F(93): every Fibonacci number that fits in u64. Unlike
+// FibonacciIterator, which adds ahead of what it yields, this never overflows.
+fn fibs_in_u64() -> impl Iterator<Item = u64> {
+    // (F(k), F(k+1) if it fits)
+    let pairs = std::iter::successors(Some((0u64, Some(1u64))), |&(prev, curr)| {
+        curr.map(|curr| (curr, prev.checked_add(curr)))
+    });
+    pairs.map(|(f, _)| f)
+}
+
+// Fibonacci numbers f with low <= f <= high, lazily. The sequence ends
+// at fib(93), the last one that fits in u64, so any high is safe.
+fn fibs_in_range(low: u64, high: u64) -> impl Iterator<Item = u64> {
+    fibs_in_u64()
+        .skip_while(move |&f| f < low)
+        .take_while(move |&f| f <= high)
+}
+
+// Every Fibonacci number <= max, duplicate 1 included: [0, 1, 1, 2, ...].
+// Stops at fib(93) for large max instead of overflowing.
+fn fibs_up_to(max: u64) -> Vec<u64> {
+    let mut fibs = vec![0];
+    let (mut prev, mut curr) = (0u64, 1u64);
+    
+    while curr <= max {
+        fibs.push(curr);
+        match prev.checked_add(curr) {
+            Some(next) => {
+                prev = curr;
+                curr = next;
+            }
+            None => break,
+        }
+    }
+    
+    fibs
 }
 
 fn benchmark_approaches(n: u32) {
@@ -166,6 +506,8 @@ fn benchmark_approaches(n: u32) {
     // Const (compile-time)
     println!("\n4. Compile-time const:");
     println!("  fib(10) computed at compile time = {}", FIB_10);
+    println!("  FIBS: [u64; 11] built at compile time = {:?}", FIBS);
+    println!("  FIBS[10] = {}", FIBS[10]);
     println!("  Zero runtime cost!");
 }
 
@@ -186,6 +528,34 @@ fn demonstrate_iterator() {
         .sum();
     println!("  {}", sum);
     
+    println!("\nFibonacci numbers in a range, or up to a bound:");
+    let in_range: Vec<u64> = fibs_in_range(10, 100).collect();
+    println!("  {:?}", in_range);
+    println!("  Between 100 and 10: {:?}", fibs_in_range(100, 10).collect::<Vec<_>>());
+    println!("  Up to u64::MAX: {} terms, last {:?}",
+             fibs_in_range(0, u64::MAX).count(), fibs_in_range(0, u64::MAX).last());
+    println!("  fibs_up_to(100) = {:?}", fibs_up_to(100));
+    println!("  fibs_up_to(0)   = {:?}", fibs_up_to(0));
+    println!("  fibs_up_to(u64::MAX) has {} terms", fibs_up_to(u64::MAX).len());
+    
+    println!("\nSkipping ahead with nth() (fast doubling, O(log n)):");
+    let mut skipping = FibonacciIterator::new();
+    println!("  nth(50) = {:?}  (fib_checked(50) = {:?})",
+             skipping.nth(50), fib_checked(50));
+    println!("  next()  = {:?}  (fib(51), iteration continues)", skipping.next());
+    println!("  nth(2)  = {:?}  (fib(54))", skipping.nth(2));
+    
+    println!("\nRunning backwards from fib(10):");
+    if let Ok(backward) = BackwardFibonacci::starting_at(10) {
+        println!("  {:?}", backward.collect::<Vec<u64>>());
+    }
+    for &n in &[93u32, 94] {
+        match BackwardFibonacci::starting_at(n) {
+            Ok(mut backward) => println!("  starting_at({}) → first {:?}", n, backward.next()),
+            Err(e) => println!("  starting_at({}) → Error: {}", n, e),
+        }
+    }
+    
     println!("\nIterators are:");
     println!("  • Zero-cost abstractions");
     println!("  • Lazily evaluated");
@@ -193,6 +563,141 @@ fn demonstrate_iterator() {
     println!("  • As fast as hand-written loops!");
 }
 
+fn demonstrate_golden_ratio() {
+    println!("\n{}", "=".repeat(60));
+    println!("CONVERGENCE TO THE GOLDEN RATIO");
+    println!("{}", "=".repeat(60));
+    
+    let table = fib_ratio_table(30);
+    println!("\n  {:>5}  {:>10}  {:>18}", "n", "fib(n)", "fib(n)/fib(n-1)");
+    for &(i, value, ratio) in table.iter().take(8) {
+        println!("  {:>5}  {:>10}  {:>18.12}", i, value, ratio);
+    }
+    println!("  {:>5}  {:>10}  {:>18}", "...", "...", "...");
+    if let Some(&(i, value, ratio)) = table.last() {
+        println!("  {:>5}  {:>10}  {:>18.12}", i, value, ratio);
+    }
+    
+    let phi = (1.0 + 5f64.sqrt()) / 2.0;
+    println!("\n  {} rows, φ = {:.12}", table.len(), phi);
+    let full = fib_ratio_table(200);
+    if let Some(&(i, value, _)) = full.last() {
+        println!("  fib_ratio_table(200) stops at u64's limit: {} rows, last fib({}) = {}",
+                 full.len(), i, value);
+    }
+    
+    println!("\nClosed form: fib(n) = round(φⁿ / √5)");
+    let exact_through = (0..=93u32)
+        .take_while(|&n| fib_checked(n) == Ok(fib_closed_form(n)))
+        .last()
+        .unwrap_or(0);
+    println!("  Matches the iterative result for n = 0..={}", exact_through);
+    let n = exact_through + 1;
+    if let Ok(exact) = fib_checked(n) {
+        println!("  fib({}) exact = {}, closed form = {}  (f64 runs out of bits)",
+                 n, exact, fib_closed_form(n));
+    }
+}
+
+fn demonstrate_pisano() {
+    println!("\n{}", "=".repeat(60));
+    println!("PISANO PERIODS - FIBONACCI MOD m REPEATS");
+    println!("{}", "=".repeat(60));
+    
+    println!("\n  {:>4}  {:>6}  first values", "m", "period");
+    for &m in &[2u64, 3, 5, 10] {
+        let sequence = pisano_sequence(m);
+        let preview: Vec<u64> = sequence.iter().take(12).cloned().collect();
+        let more = if sequence.len() > preview.len() { "..." } else { "" };
+        println!("  {:>4}  {:>6}  {:?}{}", m, pisano_period(m), preview, more);
+    }
+    
+    println!("\nMatrix power mod m vs one lap of the Pisano cycle:");
+    for &(n, m) in &[(10u64, 7u64), (100, 10), (12_345, 1_000),
+                     (1_000_000_000_000_000_000, 10), (u64::MAX, 1_000_000_007)] {
+        let fast = fib_mod_matrix(n, m);
+        let check = if m < 1_000_000 {
+            let sequence = pisano_sequence(m);
+            let expected = sequence[(n % sequence.len() as u64) as usize];
+            format!("pisano agrees: {}", fast == expected)
+        } else {
+            "too large to iterate".to_string()
+        };
+        println!("  fib({}) mod {} = {}  ({})", n, m, fast, check);
+    }
+    
+    println!("\nLast digits of Fibonacci numbers cycle every 60 terms!");
+}
+
+fn demonstrate_overflow() {
+    println!("\n{}", "=".repeat(60));
+    println!("OVERFLOW - CHECKED ARITHMETIC");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nu64 holds Fibonacci numbers up to fib(93):");
+    for &n in &[93u32, 94, 100] {
+        match fib_checked(n) {
+            Ok(value) => println!("  fib({}) = {}", n, value),
+            Err(e) => println!("  fib({}) → Error: {}", n, e),
+        }
+    }
+    
+    println!("\nFibError implements std::error::Error:");
+    if let Err(e) = fib_checked(94) {
+        let boxed: Box<dyn Error> = Box::new(e);
+        println!("  Box<dyn Error>: {}", boxed);
+    }
+    
+    println!("\nchecked_add returns Option<u64> - no silent wraparound!");
+}
+
+fn demonstrate_bounded_recursion() {
+    println!("\n{}", "=".repeat(60));
+    println!("BOUNDED RECURSION - NO STACK OVERFLOW");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nfib(n) needs n stack frames:");
+    for &(n, max_depth) in &[(20u32, 10usize), (20, 20), (25, 100)] {
+        match fib_recursive_bounded(n, max_depth) {
+            Ok(value) => println!("  fib({}) with {} frames = {}", n, max_depth, value),
+            Err(e) => println!("  fib({}) with {} frames → Error: {}", n, max_depth, e),
+        }
+    }
+    
+    println!("\nA depth budget turns a crash into a Result");
+}
+
+fn demonstrate_global_cache() {
+    println!("\n{}", "=".repeat(60));
+    println!("GLOBAL CACHE - OnceLock<Mutex<HashMap>>");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nFirst call fills the cache, second call reuses it:");
+    let first = fib_cached(50);
+    let second = fib_cached(50);
+    println!("  fib_cached(50) = {:?}", first);
+    println!("  fib_cached(50) = {:?} (same: {})", second, first == second);
+    
+    println!("\nCalling from several threads at once:");
+    let handles: Vec<_> = (0..4u32)
+        .map(|t| thread::spawn(move || (60 + t, fib_cached(60 + t))))
+        .collect();
+    for handle in handles {
+        let (n, value) = handle.join().unwrap();
+        println!("  thread: fib_cached({}) = {:?}", n, value);
+    }
+    
+    println!("\nOverflow is an error, and the cache stays usable:");
+    match fib_cached(100) {
+        Ok(value) => println!("  fib_cached(100) = {}", value),
+        Err(e) => println!("  fib_cached(100) → Error: {}", e),
+    }
+    println!("  fib_cached(0)   = {:?}", fib_cached(0));
+    
+    println!("\nOnceLock initializes exactly once, Mutex serializes access");
+    println!("No external crates (lazy_static) needed!");
+}
+
 fn demonstrate_ownership() {
     println!("\n{}", "=".repeat(60));
     println!("OWNERSHIP IN RECURSION");
@@ -222,9 +727,20 @@ fn main() {
     let result = fib_recursive_naive(n, 0);
     println!("\nFinal result: {}", result);
     
+    println!("\n=== Same Call Tree, Recorded as Data ===");
+    let (result, events) = fib_traced(3);
+    println!("fib(3) = {} with {} events ({} calls):", result, events.len(), events.len() / 2);
+    println!("  {:?}", events);
+    
     println!("\n=== Iterative Approach ===");
     let result = fib_iterative(n);
     println!("Final result: {}", result);
+    println!("Whole sequence to fib(10): {:?}", fib_sequence(10));
+    println!("Sequence to fib(0): {:?}", fib_sequence(0));
+    match fib_sequence(94) {
+        Ok(sequence) => println!("Sequence to fib(94): {} values", sequence.len()),
+        Err(e) => println!("Sequence to fib(94) → Error: {}", e),
+    }
     
     println!("\n=== Memoized Recursive ===");
     let result = fib_memoized(n, true);
@@ -232,6 +748,11 @@ fn main() {
     
     benchmark_approaches(20);
     demonstrate_iterator();
+    demonstrate_golden_ratio();
+    demonstrate_pisano();
+    demonstrate_overflow();
+    demonstrate_bounded_recursion();
+    demonstrate_global_cache();
     demonstrate_ownership();
     
     println!("\n{}", "=".repeat(60));
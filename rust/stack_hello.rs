@@ -1,9 +1,15 @@
 // Stack-based Hello World in Rust
 // Demonstrates Rust's ownership system and memory safety
 
+use std::collections::BTreeSet;
 use std::fmt;
+use std::hint::black_box;
+use std::ops::Index;
+use std::time::{Duration, Instant};
 
-// Custom Stack implementation with generic types
+// Custom Stack implementation with generic types.
+// Ordering compares bottom-to-top, lexicographically (like Vec).
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 struct Stack<T> {
     items: Vec<T>,
 }
@@ -13,6 +19,12 @@ impl<T> Stack<T> {
         Stack { items: Vec::new() }
     }
     
+    // Takes over the Vec's allocation as-is - nothing is copied or cloned.
+    // The vec is read bottom-to-top: its last element becomes the top.
+    fn from_vec(items: Vec<T>) -> Self {
+        Stack { items }
+    }
+    
     fn push(&mut self, item: T) {
         self.items.push(item);
     }
@@ -32,6 +44,258 @@ impl<T> Stack<T> {
     fn peek(&self) -> Option<&T> {
         self.items.last()
     }
+    
+    // Pops n at a time until empty; the last chunk may be shorter.
+    // Panics if n is zero, like slice::chunks.
+    fn chunk_pop(&mut self, n: usize) -> Vec<Vec<T>> {
+        assert!(n > 0, "chunk size must be non-zero");
+        let mut chunks = Vec::new();
+        while !self.is_empty() {
+            let mut chunk = Vec::with_capacity(n);
+            while chunk.len() < n {
+                match self.pop() {
+                    Some(item) => chunk.push(item),
+                    None => break,
+                }
+            }
+            chunks.push(chunk);
+        }
+        chunks
+    }
+    
+    // Keeps only the top n elements, dropping from the bottom
+    fn retain_top(&mut self, n: usize) {
+        let len = self.items.len();
+        if len > n {
+            self.items.drain(..len - n);
+        }
+    }
+    
+    // Removes the element at a bottom-zero index in O(1) by moving the
+    // current top into its slot. This REORDERS the stack: the old top is
+    // no longer on top. None if index is out of range.
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index < self.items.len() {
+            Some(self.items.swap_remove(index))
+        } else {
+            None
+        }
+    }
+    
+    // Vec-style positional edits, bottom-zero like stack[index]. Not
+    // stack operations - O(n) shifts - but handy for setting up a state.
+    // Panic when out of range: insert allows index == size(), remove doesn't.
+    fn insert(&mut self, index: usize, item: T) {
+        self.items.insert(index, item);
+    }
+    
+    fn remove(&mut self, index: usize) -> T {
+        self.items.remove(index)
+    }
+    
+    // Splits into (matching, non-matching), each keeping bottom-to-top order
+    fn partition<F: FnMut(&T) -> bool>(self, pred: F) -> (Stack<T>, Stack<T>) {
+        let (matches, rest): (Vec<T>, Vec<T>) =
+            self.items.into_iter().partition(pred);
+        (Stack::from(matches), Stack::from(rest))
+    }
+    
+    // Bottom-to-top, the order items were pushed
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+    
+    // Top-to-bottom, the order pop() would return them
+    fn top_down(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().rev()
+    }
+    
+    // Clones of up to n elements from the top, top-down; stack unchanged
+    fn take(&self, n: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.top_down().take(n).cloned().collect()
+    }
+    
+    // Up to n elements from the bottom, bottom-to-top
+    fn peek_bottom_n(&self, n: usize) -> &[T] {
+        &self.items[..n.min(self.items.len())]
+    }
+    
+    // Overlapping runs of `size` adjacent items, bottom-to-top.
+    // Panics if size is zero, like slice::windows.
+    fn windows(&self, size: usize) -> impl Iterator<Item = &[T]> {
+        self.items.windows(size)
+    }
+    
+    // True if pattern occurs as a contiguous run, read bottom-to-top.
+    // An empty pattern is always found.
+    fn contains_subsequence(&self, pattern: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        pattern.is_empty() || self.items.windows(pattern.len()).any(|run| run == pattern)
+    }
+    
+    // (index, item) pairs, bottom-to-top. index is the same bottom-zero
+    // position used by stack[index] and get(), so pairs can be fed back in.
+    fn indexed_iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.items.iter().enumerate()
+    }
+    
+    // (depth, item) pairs where depth 0 is the top
+    fn iter_with_depth(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.items.iter().rev().enumerate()
+    }
+    
+    // Removes every element matching pred and returns them bottom-to-top;
+    // the rest stay in place, in order
+    fn drain_filter<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let (removed, kept): (Vec<T>, Vec<T>) =
+            std::mem::take(&mut self.items).into_iter().partition(|item| pred(item));
+        self.items = kept;
+        removed
+    }
+    
+    // Replaces each element with f(element), dropping it on None.
+    // Order is preserved; collecting a Vec's own IntoIter back into a
+    // Vec<T> reuses the original allocation.
+    fn filter_map_in_place<F: FnMut(T) -> Option<T>>(&mut self, f: F) {
+        let items = std::mem::take(&mut self.items);
+        self.items = items.into_iter().filter_map(f).collect();
+    }
+    
+    // Zips both stacks bottom-to-top: self[0], other[0], self[1],
+    // other[1], ... The longer stack's extra items go on top, in order.
+    fn interleave(&mut self, other: Stack<T>) {
+        let mut mine = std::mem::take(&mut self.items).into_iter();
+        let mut theirs = other.items.into_iter();
+        loop {
+            match (mine.next(), theirs.next()) {
+                (None, None) => break,
+                (a, b) => self.items.extend(a.into_iter().chain(b)),
+            }
+        }
+    }
+    
+    // New stack of f(&item), same positions; self is left untouched
+    fn map_ref<U, F: FnMut(&T) -> U>(&self, f: F) -> Stack<U> {
+        Stack::from_vec(self.items.iter().map(f).collect())
+    }
+    
+    // Pops everything top-to-bottom into an accumulator, leaving the
+    // stack empty (but still usable)
+    fn fold_pop<B, F: FnMut(B, T) -> B>(&mut self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        while let Some(item) = self.pop() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+    
+    // Combines everything into one value, popping top-down:
+    // f(f(top, next), next_next)... None if the stack is empty
+    fn reduce<F: FnMut(T, T) -> T>(self, f: F) -> Option<T> {
+        self.into_iter().reduce(f)
+    }
+    
+    // Bottom-to-top, the same order From<Vec<T>> expects
+    fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+    
+    // Releases spare capacity but keeps room for at least min_capacity
+    // items (or the current size, if larger) - see Vec::shrink_to
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.items.shrink_to(min_capacity);
+    }
+    
+    // Swaps in a new top and hands back the old one. Unlike pop + push,
+    // an empty stack stays empty (the item is dropped) and None is returned.
+    fn replace_top(&mut self, item: T) -> Option<T> {
+        self.items.last_mut().map(|top| std::mem::replace(top, item))
+    }
+    
+    // Non-panicking counterparts of stack[index] (bottom-zero)
+    fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+    
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+    
+    // First-inserted element (front of the Vec)
+    fn bottom(&self) -> Option<&T> {
+        self.items.first()
+    }
+    
+    // (second_from_top, top) - the operands of a binary operation
+    fn peek_two(&self) -> Option<(&T, &T)> {
+        let len = self.items.len();
+        if len < 2 {
+            return None;
+        }
+        Some((&self.items[len - 2], &self.items[len - 1]))
+    }
+}
+
+impl<T> From<Vec<T>> for Stack<T> {
+    fn from(items: Vec<T>) -> Self {
+        Stack::from_vec(items)
+    }
+}
+
+// Written by hand so clone_from can reuse the destination's buffer
+// (Vec::clone_from) instead of allocating a fresh one
+impl<T: Clone> Clone for Stack<T> {
+    fn clone(&self) -> Self {
+        Stack { items: self.items.clone() }
+    }
+    
+    fn clone_from(&mut self, source: &Self) {
+        self.items.clone_from(&source.items);
+    }
+}
+
+// Consuming iterator: next() yields top-to-bottom (pop order),
+// next_back() yields bottom-to-top
+struct IntoIter<T> {
+    inner: std::vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    
+    fn next(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self.items.into_iter() }
+    }
+}
+
+// stack[0] is the BOTTOM (first pushed), stack[size() - 1] is the top.
+// Out-of-range indices panic, just like Vec.
+impl<T> Index<usize> for Stack<T> {
+    type Output = T;
+    
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
 }
 
 impl<T: fmt::Display> Stack<T> {
@@ -45,6 +309,146 @@ impl<T: fmt::Display> Stack<T> {
     }
 }
 
+// Every edit pushes the previous content, so undo is just a pop
+struct TextBuffer {
+    content: String,
+    history: Stack<String>,
+}
+
+impl TextBuffer {
+    fn new() -> Self {
+        TextBuffer { content: String::new(), history: Stack::new() }
+    }
+    
+    fn insert(&mut self, s: &str) {
+        self.history.push(self.content.clone());
+        self.content.push_str(s);
+    }
+    
+    // Returns false when there is nothing left to undo
+    fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(previous) => {
+                self.content = previous;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+// Push the first half, then pop it back against the second half.
+// For odd lengths the middle element is skipped.
+fn is_palindrome<T: PartialEq + Clone>(items: &[T]) -> bool {
+    let half = items.len() / 2;
+    let mut stack = Stack::new();
+    for item in &items[..half] {
+        stack.push(item.clone());
+    }
+    
+    let second_half = &items[items.len() - half..];
+    second_half.iter().all(|item| stack.pop().as_ref() == Some(item))
+}
+
+// Remainders come out least-significant first; popping reverses them
+fn to_binary(mut n: u32) -> String {
+    if n == 0 {
+        return String::from("0");
+    }
+    
+    let mut bits: Stack<u8> = Stack::new();
+    while n > 0 {
+        bits.push((n % 2) as u8);
+        n /= 2;
+    }
+    
+    let mut binary = String::with_capacity(bits.size());
+    while let Some(bit) = bits.pop() {
+        binary.push(if bit == 1 { '1' } else { '0' });
+    }
+    binary
+}
+
+// Same idea for any base from 2 to 36 (digits 0-9 then a-z)
+fn to_base(mut n: u32, base: u32) -> Result<String, String> {
+    if !(2..=36).contains(&base) {
+        return Err(format!("Unsupported base {} (expected 2-36)", base));
+    }
+    if n == 0 {
+        return Ok(String::from("0"));
+    }
+    
+    let mut digits: Stack<char> = Stack::new();
+    while n > 0 {
+        if let Some(digit) = std::char::from_digit(n % base, base) {
+            digits.push(digit);
+        }
+        n /= base;
+    }
+    
+    let mut converted = String::with_capacity(digits.size());
+    while let Some(digit) = digits.pop() {
+        converted.push(digit);
+    }
+    Ok(converted)
+}
+
+// Run-length encoding: the output's last run acts as the stack top
+fn rle_encode<T: PartialEq + Clone>(input: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+    
+    for item in input {
+        match runs.last_mut() {
+            Some(&mut (ref value, ref mut count)) if value == item => {
+                *count += 1;
+            }
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+    
+    runs
+}
+
+fn rle_decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    let mut output = Vec::new();
+    for &(ref value, count) in runs {
+        for _ in 0..count {
+            output.push(value.clone());
+        }
+    }
+    output
+}
+
+// Times `iterations` pushes then pops through Stack<T>, then through a
+// bare Vec<T>. black_box keeps the optimizer from deleting the work.
+fn bench_stack_vs_vec(iterations: usize) -> (Duration, Duration) {
+    let start = Instant::now();
+    let mut stack = Stack::new();
+    for i in 0..iterations {
+        stack.push(black_box(i));
+    }
+    while let Some(item) = stack.pop() {
+        black_box(item);
+    }
+    let stack_time = start.elapsed();
+    
+    let start = Instant::now();
+    let mut vec = Vec::new();
+    for i in 0..iterations {
+        vec.push(black_box(i));
+    }
+    while let Some(item) = vec.pop() {
+        black_box(item);
+    }
+    let vec_time = start.elapsed();
+    
+    (stack_time, vec_time)
+}
+
 fn stack_hello_basic() {
     println!("=== Basic Stack Operations ===\n");
     
@@ -153,6 +557,295 @@ fn demonstrate_generic_stack() {
     println!("No runtime overhead like dynamic typing");
 }
 
+fn demonstrate_stack_api() {
+    println!("\n{}", "=".repeat(60));
+    println!("STACK API - INSPECTING WITHOUT POPPING");
+    println!("{}", "=".repeat(60));
+    
+    let mut stack = Stack::new();
+    for i in 1..=3 {
+        stack.push(i);
+    }
+    print!("\n  Stack: ");
+    stack.display();
+    println!();
+    
+    println!("\n1. Both ends:");
+    println!("  peek()   = {:?}  (top, last pushed)", stack.peek());
+    println!("  bottom() = {:?}  (first pushed)", stack.bottom());
+    
+    let empty: Stack<i32> = Stack::new();
+    println!("  Empty stack: peek() = {:?}, bottom() = {:?}",
+             empty.peek(), empty.bottom());
+    println!("  peek_two() = {:?}  (operands for a binary op)",
+             stack.peek_two());
+    let mut single = Stack::new();
+    single.push(1);
+    println!("  peek_two() on [1] = {:?}", single.peek_two());
+    println!("  replace_top(9) on [1] = {:?}, now peek() = {:?}, size = {}",
+             single.replace_top(9), single.peek(), single.size());
+    let mut nothing: Stack<i32> = Stack::new();
+    println!("  replace_top(9) on [] = {:?}, still empty: {}",
+             nothing.replace_top(9), nothing.is_empty());
+    
+    println!("\n2. Indexing (bottom-zero):");
+    let tens = Stack::from(vec![10, 20, 30]);
+    println!("  stack[0] = {}  (bottom)", tens[0]);
+    println!("  stack[1] = {}", tens[1]);
+    println!("  stack[2] = {}  (top)", tens[2]);
+    println!("  stack[3] would panic - bounds checked like Vec");
+    println!("  get(1) = {:?}, get(3) = {:?}  (no panic)", tens.get(1), tens.get(3));
+    let mut editable = Stack::from(vec![10, 20, 30]);
+    if let Some(middle) = editable.get_mut(1) {
+        *middle += 5;
+    }
+    println!("  get_mut(1) += 5 → {:?}", editable.get(1));
+    println!("  get_mut(3) = {:?}", editable.get_mut(3));
+    
+    println!("\n3. Converting to and from Vec (bottom-to-top):");
+    let mut from_vec = Stack::from(vec![1, 2, 3]);
+    println!("  Stack::from(vec![1, 2, 3]).pop() = {:?}", from_vec.pop());
+    from_vec.push(3);
+    println!("  into_vec() = {:?}  (round trip)", from_vec.into_vec());
+    
+    println!("\n4. Popping in groups:");
+    let mut five = Stack::from(vec![1, 2, 3, 4, 5]);
+    println!("  [1, 2, 3, 4, 5].chunk_pop(2) = {:?}", five.chunk_pop(2));
+    println!("  Stack afterwards is empty: {}", five.is_empty());
+    
+    println!("\n5. Bounding the depth:");
+    let mut bounded = Stack::from(vec![1, 2, 3, 4, 5]);
+    bounded.retain_top(2);
+    println!("  [1, 2, 3, 4, 5].retain_top(2)  = {:?}", bounded.into_vec());
+    let mut unchanged = Stack::from(vec![1, 2, 3, 4, 5]);
+    unchanged.retain_top(10);
+    println!("  [1, 2, 3, 4, 5].retain_top(10) = {:?}", unchanged.into_vec());
+    
+    println!("\n6. Editing the middle (swap_remove is O(1), insert/remove O(n)):");
+    let mut letters = Stack::from(vec!['a', 'b', 'c', 'd']);
+    println!("  ['a', 'b', 'c', 'd'].swap_remove(1) = {:?}", letters.swap_remove(1));
+    println!("  Old top 'd' now sits at index 1: {:?}", letters.into_vec());
+    let mut shifted = Stack::from(vec![1, 2, 3]);
+    shifted.insert(0, 0);
+    println!("  [1, 2, 3].insert(0, 0) → {:?}  (new bottom)", shifted.items);
+    println!("  .remove(2) = {}  → {:?}  (order kept, O(n))", shifted.remove(2), shifted.items);
+    
+    println!("\n7. Splitting by a predicate:");
+    let numbers = Stack::from(vec![1, 2, 3, 4, 5]);
+    let (evens, odds) = numbers.partition(|n| n % 2 == 0);
+    println!("  [1, 2, 3, 4, 5].partition(even) = ({:?}, {:?})",
+             evens.into_vec(), odds.into_vec());
+    let mut filtered = Stack::from(vec![1, 2, 3, 4, 5]);
+    let removed = filtered.drain_filter(|n| n % 2 == 0);
+    println!("  [1, 2, 3, 4, 5].drain_filter(even) = {:?}, leaving {:?}",
+             removed, filtered.into_vec());
+    
+    println!("\n8. Zero-copy conversion (works for non-Clone types):");
+    struct Ticket(u32);
+    let tickets = vec![Ticket(1), Ticket(2)];
+    let capacity = tickets.capacity();
+    let mut ticket_stack = Stack::from_vec(tickets);
+    println!("  Capacity kept: {} → {}", capacity, ticket_stack.items.capacity());
+    if let Some(ticket) = ticket_stack.pop() {
+        println!("  pop() = Ticket({})", ticket.0);
+    }
+    
+    println!("\n9. Labeled by depth (0 = top) and by index (0 = bottom):");
+    let labeled = Stack::from(vec![1, 2, 3]);
+    for (depth, item) in labeled.iter_with_depth() {
+        println!("  depth {}: {}", depth, item);
+    }
+    
+    for (index, item) in labeled.indexed_iter() {
+        println!("  index {}: {}  (stack[{}] = {})", index, item, index, labeled[index]);
+    }
+    
+    println!("\n10. Consuming from both ends:");
+    let mut both_ends = Stack::from(vec![1, 2, 3, 4]).into_iter();
+    println!("  next()      = {:?}  (top)", both_ends.next());
+    println!("  next_back() = {:?}  (bottom)", both_ends.next_back());
+    println!("  next()      = {:?}", both_ends.next());
+    println!("  next_back() = {:?}", both_ends.next_back());
+    println!("  next()      = {:?}  (exhausted)", both_ends.next());
+    
+    println!("\n11. Borrowing iterators (stack unchanged):");
+    let ordered = Stack::from(vec![1, 2, 3]);
+    println!("  iter()     = {:?}  (push order)", ordered.iter().collect::<Vec<_>>());
+    println!("  top_down() = {:?}  (pop order)", ordered.top_down().collect::<Vec<_>>());
+    
+    println!("\n12. Comparing stacks (bottom-to-top, lexicographic):");
+    println!("  [1, 2] < [1, 3]: {}", Stack::from(vec![1, 2]) < Stack::from(vec![1, 3]));
+    println!("  [1] < [1, 2]:    {}", Stack::from(vec![1]) < Stack::from(vec![1, 2]));
+    let mut set = BTreeSet::new();
+    set.insert(Stack::from(vec![2]));
+    set.insert(Stack::from(vec![1, 3]));
+    set.insert(Stack::from(vec![1, 2]));
+    print!("  BTreeSet order: ");
+    for stack in &set {
+        stack.display();
+        print!(" ");
+    }
+    println!();
+    
+    println!("\n13. Transforming in place, or into a new stack:");
+    let mut tokens = Stack::from(vec![1, 2, 3, 4]);
+    tokens.filter_map_in_place(|n| if n % 2 == 0 { Some(n * 2) } else { None });
+    print!("  [1, 2, 3, 4] → double evens, drop odds → ");
+    tokens.display();
+    println!();
+    let originals = Stack::from(vec![1, 2, 3]);
+    let squares = originals.map_ref(|n| n * n);
+    print!("  map_ref(square) → ");
+    squares.display();
+    print!(", original still ");
+    originals.display();
+    println!();
+    
+    let mut odds = Stack::from(vec![1, 3, 5]);
+    odds.interleave(Stack::from(vec![2, 4]));
+    print!("  [1, 3, 5].interleave([2, 4]) → ");
+    odds.display();
+    println!();
+    
+    println!("\n14. Reducing to a single value:");
+    println!("  [1, 2, 3, 4].reduce(+) = {:?}", Stack::from(vec![1, 2, 3, 4]).reduce(|a, b| a + b));
+    println!("  [].reduce(+)           = {:?}", Stack::<i32>::new().reduce(|a, b| a + b));
+    let mut draining = Stack::from(vec![1, 2, 3]);
+    let total = draining.fold_pop(0, |sum, n| sum + n);
+    println!("  [1, 2, 3].fold_pop(0, +) = {}, stack empty afterwards: {}",
+             total, draining.is_empty());
+    
+    println!("\n15. Snapshots of either end (non-consuming):");
+    let deep = Stack::from(vec![1, 2, 3, 4, 5]);
+    println!("  take(2)  = {:?}", deep.take(2));
+    println!("  take(10) = {:?}", deep.take(10));
+    println!("  peek_bottom_n(2)  = {:?}  (the base)", deep.peek_bottom_n(2));
+    println!("  peek_bottom_n(10) = {:?}", deep.peek_bottom_n(10));
+    println!("  stack still has {} items", deep.size());
+    
+    println!("\n16. Adjacent runs (windows):");
+    let sequence = Stack::from(vec![1, 2, 3, 4]);
+    let pairs: Vec<&[i32]> = sequence.windows(2).collect();
+    println!("  {:?}", pairs);
+    for pattern in &[vec![2, 3], vec![3, 2], vec![1, 2, 3, 4], vec![]] {
+        println!("  contains_subsequence({:?}) = {}",
+                 pattern, sequence.contains_subsequence(pattern));
+    }
+    
+    println!("\n17. clone_from reuses the existing allocation:");
+    let source = Stack::from(vec![7, 8, 9]);
+    let mut snapshot: Stack<i32> = Stack::from(Vec::with_capacity(64));
+    let before = snapshot.items.as_ptr();
+    snapshot.clone_from(&source);
+    println!("  snapshot == source: {}", snapshot == source);
+    println!("  capacity {}, same buffer: {}",
+             snapshot.items.capacity(), before == snapshot.items.as_ptr());
+    
+    println!("\n18. shrink_to keeps some headroom:");
+    let mut scratch = Stack::new();
+    for n in 0..1000 {
+        scratch.push(n);
+    }
+    while scratch.size() > 10 {
+        scratch.pop();
+    }
+    let peak = scratch.items.capacity();
+    scratch.shrink_to(100);
+    println!("  {} items, capacity {} → {} (target 100)",
+             scratch.size(), peak, scratch.items.capacity());
+}
+
+fn demonstrate_undo() {
+    println!("\n{}", "=".repeat(60));
+    println!("UNDO - A STACK OF PAST STATES");
+    println!("{}", "=".repeat(60));
+    
+    let mut buffer = TextBuffer::new();
+    println!();
+    buffer.insert("Hello");
+    println!("  insert(\"Hello\")  → {:?}", buffer.content());
+    buffer.insert(" World");
+    println!("  insert(\" World\") → {:?}", buffer.content());
+    buffer.undo();
+    println!("  undo()           → {:?}", buffer.content());
+    buffer.undo();
+    println!("  undo()           → {:?}", buffer.content());
+    println!("  undo() again?    → {} (history empty)", buffer.undo());
+    
+    println!("\nMost recent edit is undone first - LIFO!");
+}
+
+fn demonstrate_palindromes() {
+    println!("\n{}", "=".repeat(60));
+    println!("PALINDROMES - HALF A STACK IS ENOUGH");
+    println!("{}", "=".repeat(60));
+    
+    println!();
+    for word in &["racecar", "hello", "abba", ""] {
+        let chars: Vec<char> = word.chars().collect();
+        println!("  {:<9} → {}", format!("{:?}", word), is_palindrome(&chars));
+    }
+    println!("  {:<9} → {}", "[1, 2, 1]", is_palindrome(&[1, 2, 1]));
+    
+    println!("\nThe stack reverses the first half so it can be");
+    println!("compared element by element with the second half");
+}
+
+fn demonstrate_binary_conversion() {
+    println!("\n{}", "=".repeat(60));
+    println!("DECIMAL TO BINARY - REVERSAL FOR FREE");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nRemainders of 5 / 2: 1, 0, 1 (pushed low bit first)");
+    for &n in &[0u32, 5, 255] {
+        println!("  {:>3} → {}", n, to_binary(n));
+    }
+    
+    println!("\nAny base from 2 to 36:");
+    for &(n, base) in &[(255u32, 16u32), (10, 2), (35, 36), (10, 1)] {
+        match to_base(n, base) {
+            Ok(digits) => println!("  {} in base {} → {}", n, base, digits),
+            Err(e) => println!("  {} in base {} → Error: {}", n, base, e),
+        }
+    }
+    
+    println!("\nDivision yields digits backwards - the stack flips them back");
+}
+
+fn demonstrate_rle() {
+    println!("\n{}", "=".repeat(60));
+    println!("RUN-LENGTH ENCODING - COMPRESSING WITH THE TOP");
+    println!("{}", "=".repeat(60));
+    
+    let input = vec!['a', 'a', 'b', 'c', 'c', 'c'];
+    let encoded = rle_encode(&input);
+    let decoded = rle_decode(&encoded);
+    
+    println!("\n  Input:   {:?}", input);
+    println!("  Encoded: {:?}", encoded);
+    println!("  Decoded: {:?}", decoded);
+    println!("  Round trip matches: {}", decoded == input);
+    
+    println!("\nOnly the top run is ever inspected:");
+    println!("  • Same value → bump the top's count");
+    println!("  • New value  → push a new run");
+}
+
+fn demonstrate_zero_cost() {
+    println!("\n{}", "=".repeat(60));
+    println!("ZERO-COST WRAPPER - Stack<T> vs Vec<T>");
+    println!("{}", "=".repeat(60));
+    
+    let iterations = 1_000_000;
+    let (stack_time, vec_time) = bench_stack_vs_vec(iterations);
+    println!("\n  {} pushes + pops:", iterations);
+    println!("  Stack<T>: {:?}", stack_time);
+    println!("  Vec<T>:   {:?}", vec_time);
+    
+    println!("\nThe wrapper's methods inline away - timings should be close");
+    println!("(build with -O for meaningful numbers)");
+}
+
 fn demonstrate_safety() {
     println!("\n{}", "=".repeat(60));
     println!("RUST SAFETY GUARANTEES");
@@ -190,6 +883,12 @@ fn main() {
     stack_hello_basic();
     demonstrate_ownership();
     demonstrate_generic_stack();
+    demonstrate_stack_api();
+    demonstrate_undo();
+    demonstrate_palindromes();
+    demonstrate_binary_conversion();
+    demonstrate_rle();
+    demonstrate_zero_cost();
     demonstrate_safety();
     
     println!("\n{}", "=".repeat(60));
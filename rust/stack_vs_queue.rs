@@ -1,7 +1,13 @@
 // Stack vs Queue in Rust
 // Demonstrates Rust's type system and standard library collections
 
-use std::collections::{VecDeque, LinkedList};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque, LinkedList};
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::iter::FromIterator;
+use std::str::FromStr;
 
 // Generic Stack implementation
 struct Stack<T> {
@@ -24,6 +30,18 @@ impl<T> Stack<T> {
     fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
+    
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+    
+    fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+    
+    fn into_vec(self) -> Vec<T> {
+        self.items
+    }
 }
 
 // Generic Queue implementation
@@ -36,6 +54,11 @@ impl<T> Queue<T> {
         Queue { items: VecDeque::new() }
     }
     
+    // Takes over the VecDeque as-is - front stays front, nothing is cloned
+    fn from_vecdeque(items: VecDeque<T>) -> Self {
+        Queue { items }
+    }
+    
     fn enqueue(&mut self, item: T) {
         self.items.push_back(item);
     }
@@ -47,6 +70,393 @@ impl<T> Queue<T> {
     fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
+    
+    // Alternates a, b, a, b, ... then appends whatever is left over
+    fn merge(mut a: Queue<T>, mut b: Queue<T>) -> Queue<T> {
+        let mut merged = Queue::new();
+        while !a.is_empty() || !b.is_empty() {
+            if let Some(item) = a.dequeue() {
+                merged.enqueue(item);
+            }
+            if let Some(item) = b.dequeue() {
+                merged.enqueue(item);
+            }
+        }
+        merged
+    }
+    
+    // Look-ahead without dequeuing; 0 is the front
+    fn peek_nth(&self, n: usize) -> Option<&T> {
+        self.items.get(n)
+    }
+    
+    // Front-to-back (dequeue order), leaving the queue as it is
+    fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.items.iter()
+    }
+    
+    // Front-to-back; items can be changed but not reordered
+    fn iter_mut(&mut self) -> std::collections::vec_deque::IterMut<'_, T> {
+        self.items.iter_mut()
+    }
+    
+    // Front-to-back, the same order From<Vec<T>> expects
+    fn into_vec(self) -> Vec<T> {
+        self.items.into()
+    }
+    
+    // The first `index` items (front first) and the rest, order kept.
+    // Panics if index > len, like VecDeque::split_off.
+    fn split_at(mut self, index: usize) -> (Queue<T>, Queue<T>) {
+        let back = self.items.split_off(index);
+        (self, Queue::from_vecdeque(back))
+    }
+    
+    // Dequeue from the front until pred fails; the failing item stays queued
+    fn drain_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut drained = Vec::new();
+        while self.items.front().is_some_and(&mut pred) {
+            if let Some(item) = self.items.pop_front() {
+                drained.push(item);
+            }
+        }
+        drained
+    }
+}
+
+// k-way round robin: one item from each queue in turn. The queues
+// themselves wait in a queue, rejoining the back until they run dry.
+fn round_robin_merge<T>(queues: Vec<Queue<T>>) -> Queue<T> {
+    let mut turns: Queue<Queue<T>> = queues.into_iter().collect();
+    let mut merged = Queue::new();
+    
+    while let Some(mut queue) = turns.dequeue() {
+        if let Some(item) = queue.dequeue() {
+            merged.enqueue(item);
+            turns.enqueue(queue);
+        }
+    }
+    merged
+}
+
+// n people stand in a circle and every k-th is eliminated; returns the
+// survivor's 0-based position. Skipped people go to the back of the queue.
+fn josephus(n: usize, k: usize) -> usize {
+    assert!(n > 0 && k > 0, "josephus needs at least one person and k > 0");
+    let mut circle: Queue<usize> = (0..n).collect();
+    
+    loop {
+        for _ in 1..k {
+            if let Some(person) = circle.dequeue() {
+                circle.enqueue(person);
+            }
+        }
+        let eliminated = circle.dequeue();
+        if circle.is_empty() {
+            return eliminated.unwrap_or(0);
+        }
+    }
+}
+
+// Queue → stack → queue: LIFO undoes FIFO order
+fn reverse_queue<T>(queue: &mut Queue<T>) {
+    let mut stack = Stack::new();
+    while let Some(item) = queue.dequeue() {
+        stack.push(item);
+    }
+    while let Some(item) = stack.pop() {
+        queue.enqueue(item);
+    }
+}
+
+// FIFO built from two LIFOs - the classic amortized O(1) exercise.
+// Each item is moved from inbox to outbox at most once.
+struct TwoStackQueue<T> {
+    inbox: Stack<T>,
+    outbox: Stack<T>,
+    transfers: usize,
+}
+
+impl<T> TwoStackQueue<T> {
+    fn new() -> Self {
+        TwoStackQueue {
+            inbox: Stack::new(),
+            outbox: Stack::new(),
+            transfers: 0,
+        }
+    }
+    
+    fn enqueue(&mut self, item: T) {
+        self.inbox.push(item);
+    }
+    
+    fn dequeue(&mut self) -> Option<T> {
+        if self.outbox.is_empty() && !self.inbox.is_empty() {
+            // Reversing the inbox puts the oldest item on top
+            while let Some(item) = self.inbox.pop() {
+                self.outbox.push(item);
+            }
+            self.transfers += 1;
+        }
+        self.outbox.pop()
+    }
+}
+
+// Discrete-event queue: items are released once their time has come.
+// Equal times are released in scheduling order.
+struct DelayQueue<T> {
+    heap: BinaryHeap<Scheduled<T>>,
+    next_seq: u64,
+}
+
+struct Scheduled<T> {
+    at: u64,
+    seq: u64,
+    item: T,
+}
+
+// Reversed so the max-heap pops the EARLIEST (at, seq) first
+impl<T> Ord for Scheduled<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.at, other.seq).cmp(&(self.at, self.seq))
+    }
+}
+
+impl<T> PartialOrd for Scheduled<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Scheduled<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Scheduled<T> {}
+
+impl<T> DelayQueue<T> {
+    fn new() -> Self {
+        DelayQueue { heap: BinaryHeap::new(), next_seq: 0 }
+    }
+    
+    fn schedule(&mut self, at: u64, item: T) {
+        self.heap.push(Scheduled { at, seq: self.next_seq, item });
+        self.next_seq += 1;
+    }
+    
+    // Everything due at or before now, in time order
+    fn poll(&mut self, now: u64) -> Vec<T> {
+        let mut due = Vec::new();
+        while self.heap.peek().is_some_and(|next| next.at <= now) {
+            if let Some(entry) = self.heap.pop() {
+                due.push(entry.item);
+            }
+        }
+        due
+    }
+}
+
+// Sliding-window rate limiter: a queue of recent request times.
+// Oldest timestamps expire from the front, new ones join at the back.
+struct TokenBucket {
+    timestamps: VecDeque<u64>,
+}
+
+impl TokenBucket {
+    fn new() -> Self {
+        TokenBucket { timestamps: VecDeque::new() }
+    }
+    
+    // Allows at most `limit` requests in any `window`-long span
+    fn allow(&mut self, now: u64, window: u64, limit: usize) -> bool {
+        let cutoff = now.saturating_sub(window);
+        while self.timestamps.front().is_some_and(|&t| t < cutoff) {
+            self.timestamps.pop_front();
+        }
+        
+        if self.timestamps.len() < limit {
+            self.timestamps.push_back(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// Streaming mean over the last `window` values. The running sum is
+// adjusted as values enter at the back and leave from the front, using
+// compensated (Kahan-Babuska/Neumaier) summation: without it, a huge value
+// entering and leaving wipes out the small ones added while it was there.
+// Plain Kahan isn't enough - removing the huge value is itself an addition
+// bigger than the sum, which is the case Neumaier's variant handles.
+struct MovingAverage {
+    values: VecDeque<f64>,
+    window: usize,
+    sum: f64,
+    compensation: f64,  // low-order bits lost from sum, added back on read
+}
+
+impl MovingAverage {
+    fn new(window: usize) -> Self {
+        assert!(window > 0, "window must hold at least one value");
+        MovingAverage {
+            values: VecDeque::with_capacity(window),
+            window,
+            sum: 0.0,
+            compensation: 0.0,
+        }
+    }
+    
+    fn next(&mut self, value: f64) -> f64 {
+        if self.values.len() == self.window {
+            if let Some(oldest) = self.values.pop_front() {
+                self.add(-oldest);
+            }
+        }
+        self.values.push_back(value);
+        self.add(value);
+        (self.sum + self.compensation) / self.values.len() as f64
+    }
+    
+    fn add(&mut self, value: f64) {
+        let total = self.sum + value;
+        // Whatever the larger operand couldn't absorb from the smaller one
+        self.compensation += if self.sum.abs() >= value.abs() {
+            (self.sum - total) + value
+        } else {
+            (value - total) + self.sum
+        };
+        self.sum = total;
+    }
+}
+
+// What a full MessageBuffer does with one more message
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OverflowPolicy {
+    DropOldest,  // evict the front to make room
+    DropNewest,  // silently discard the incoming message
+    Reject,      // refuse it and hand it back to the sender
+}
+
+// Bounded FIFO of messages with a fixed backpressure strategy
+struct MessageBuffer {
+    messages: VecDeque<String>,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl MessageBuffer {
+    fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "buffer must hold at least one message");
+        MessageBuffer { messages: VecDeque::with_capacity(capacity), capacity, policy }
+    }
+    
+    // Err(msg) only under Reject, returning the message to the caller
+    fn push(&mut self, msg: String) -> Result<(), String> {
+        if self.messages.len() == self.capacity {
+            match self.policy {
+                OverflowPolicy::DropOldest => {
+                    self.messages.pop_front();
+                }
+                OverflowPolicy::DropNewest => return Ok(()),
+                OverflowPolicy::Reject => return Err(msg),
+            }
+        }
+        self.messages.push_back(msg);
+        Ok(())
+    }
+    
+    fn pop(&mut self) -> Option<String> {
+        self.messages.pop_front()
+    }
+}
+
+// Multi-level feedback queue: level 0 is the highest priority. New tasks
+// start at the top; a task that used up its time slice is requeued one
+// level lower, so interactive work overtakes long-running work.
+struct MlfqScheduler<T> {
+    levels: Vec<Queue<T>>,
+}
+
+impl<T> MlfqScheduler<T> {
+    fn new(level_count: usize) -> Self {
+        assert!(level_count > 0, "scheduler needs at least one level");
+        MlfqScheduler { levels: (0..level_count).map(|_| Queue::new()).collect() }
+    }
+    
+    fn submit(&mut self, task: T) {
+        self.levels[0].enqueue(task);
+    }
+    
+    // Demotes a task served from `level`; the lowest level keeps its own
+    fn requeue_lower(&mut self, level: usize, task: T) {
+        let lower = (level + 1).min(self.levels.len() - 1);
+        self.levels[lower].enqueue(task);
+    }
+}
+
+// next() serves the front of the highest non-empty level, with the
+// level it came from (needed to demote it)
+impl<T> Iterator for MlfqScheduler<T> {
+    type Item = (usize, T);
+    
+    fn next(&mut self) -> Option<(usize, T)> {
+        self.levels
+            .iter_mut()
+            .enumerate()
+            .find_map(|(level, queue)| queue.dequeue().map(|task| (level, task)))
+    }
+}
+
+// The vec is read front-to-back: its first element is dequeued first
+impl<T> From<Vec<T>> for Queue<T> {
+    fn from(items: Vec<T>) -> Self {
+        Queue::from_vecdeque(items.into())
+    }
+}
+
+// Items are enqueued in iteration order
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Queue { items: iter.into_iter().collect() }
+    }
+}
+
+// Double-ended: a stack or a queue depending on which ends you use
+struct Deque<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Deque<T> {
+    fn new() -> Self {
+        Deque { items: VecDeque::new() }
+    }
+    
+    fn push_front(&mut self, item: T) {
+        self.items.push_front(item);
+    }
+    
+    fn push_back(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+    
+    fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+    
+    fn pop_back(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+    
+    fn peek_front(&self) -> Option<&T> {
+        self.items.front()
+    }
+    
+    fn peek_back(&self) -> Option<&T> {
+        self.items.back()
+    }
 }
 
 fn demo_basic_operations() {
@@ -87,6 +497,23 @@ fn demo_basic_operations() {
         }
     }
     println!();
+    
+    println!("\n--- Collecting an iterator into a QUEUE ---");
+    let mut collected: Queue<i32> = (1..=5).collect();
+    print!("(1..=5).collect() dequeues: ");
+    while let Some(n) = collected.dequeue() {
+        print!("{} ", n);
+    }
+    println!();
+    
+    println!("\n--- Reversing a QUEUE through a STACK ---");
+    let mut queue = Queue::from(vec![1, 2, 3]);
+    reverse_queue(&mut queue);
+    print!("[1, 2, 3] reversed dequeues: ");
+    while let Some(n) = queue.dequeue() {
+        print!("{} ", n);
+    }
+    println!();
 }
 
 fn demo_standard_collections() {
@@ -125,6 +552,230 @@ fn demo_standard_collections() {
     println!("  LinkedList:  push/pop anywhere  - O(1)");
 }
 
+fn demo_deque() {
+    println!("\n{}", "=".repeat(60));
+    println!("DEQUE - ONE STRUCTURE, BOTH BEHAVIORS");
+    println!("{}", "=".repeat(60));
+    
+    let input = [1, 2, 3];
+    
+    println!("\n1. As a stack (push_back / pop_back):");
+    let mut deque = Deque::new();
+    for &n in &input {
+        deque.push_back(n);
+    }
+    println!("  Top (peek_back): {:?}", deque.peek_back());
+    print!("  Output: ");
+    while let Some(n) = deque.pop_back() {
+        print!("{} ", n);
+    }
+    println!("  (LIFO)");
+    
+    println!("\n2. As a queue (push_back / pop_front):");
+    for &n in &input {
+        deque.push_back(n);
+    }
+    println!("  Front (peek_front): {:?}", deque.peek_front());
+    print!("  Output: ");
+    while let Some(n) = deque.pop_front() {
+        print!("{} ", n);
+    }
+    println!("  (FIFO)");
+    
+    println!("\n3. Jumping the line (push_front):");
+    deque.push_back(2);
+    deque.push_front(1);
+    println!("  Front: {:?}, Back: {:?}", deque.peek_front(), deque.peek_back());
+}
+
+fn demo_queue_batches() {
+    println!("\n{}", "=".repeat(60));
+    println!("QUEUE BATCHES - DRAINING UP TO A BOUNDARY");
+    println!("{}", "=".repeat(60));
+    
+    let mut queue = Queue::from(vec![1, 2, 3, 10, 4]);
+    
+    println!("\n  Queue: [1, 2, 3, 10, 4]");
+    let batch = queue.drain_while(|&x| x < 5);
+    println!("  drain_while(x < 5) = {:?}", batch);
+    println!("  Still queued: {:?}", queue.into_vec());
+    println!("\n  10 stopped the batch - and 4 waits behind it (FIFO!)");
+    
+    println!("\n  Adopting an existing VecDeque (no Clone needed):");
+    struct Job(u32);
+    let mut deque = VecDeque::new();
+    deque.push_back(Job(1));
+    deque.push_back(Job(2));
+    let mut jobs = Queue::from_vecdeque(deque);
+    if let Some(job) = jobs.dequeue() {
+        println!("  dequeue() = Job({})", job.0);
+    }
+    
+    println!("\n  Looking ahead without dequeuing:");
+    let mut queue = Queue::from(vec![10, 20, 30]);
+    for n in 0..4 {
+        println!("  peek_nth({}) = {:?}", n, queue.peek_nth(n));
+    }
+    for item in queue.iter_mut() {
+        *item += 1;
+    }
+    let contents: Vec<&i32> = queue.iter().collect();
+    println!("  iter_mut(+1), then iter() = {:?}  (still {} queued)",
+             contents, queue.iter().count());
+    
+    println!("\n  Splitting into front and back queues:");
+    for &index in &[2, 0, 5] {
+        let (front, back) = Queue::from(vec![1, 2, 3, 4, 5]).split_at(index);
+        println!("  [1, 2, 3, 4, 5].split_at({}) = ({:?}, {:?})",
+                 index, front.into_vec(), back.into_vec());
+    }
+    
+    println!("\n  Interleaving two queues:");
+    let merged = Queue::merge(Queue::from(vec![1, 2, 3]), Queue::from(vec![4, 5]));
+    println!("  merge([1, 2, 3], [4, 5]) = {:?}", merged.into_vec());
+    let streams = vec![
+        Queue::from(vec!["a1", "a2", "a3"]),
+        Queue::from(vec!["b1"]),
+        Queue::from(vec!["c1", "c2"]),
+    ];
+    println!("  round_robin_merge(a×3, b×1, c×2) = {:?}",
+             round_robin_merge(streams).into_vec());
+    
+    println!("\n  Josephus circle (rotate k-1, remove the k-th):");
+    for &(n, k) in &[(7, 3), (1, 1), (41, 3)] {
+        println!("  josephus({}, {}) = position {} (0-based)", n, k, josephus(n, k));
+    }
+}
+
+fn demo_two_stack_queue() {
+    println!("\n{}", "=".repeat(60));
+    println!("QUEUE FROM TWO STACKS - AMORTIZED O(1)");
+    println!("{}", "=".repeat(60));
+    
+    let mut queue = TwoStackQueue::new();
+    
+    println!();
+    for n in 1..=3 {
+        queue.enqueue(n);
+        println!("  ENQUEUE {}", n);
+    }
+    println!("  DEQUEUE → {:?}  (transfers so far: {})", queue.dequeue(), queue.transfers);
+    queue.enqueue(4);
+    println!("  ENQUEUE 4");
+    println!("  DEQUEUE → {:?}  (transfers so far: {})", queue.dequeue(), queue.transfers);
+    println!("  DEQUEUE → {:?}  (transfers so far: {})", queue.dequeue(), queue.transfers);
+    println!("  DEQUEUE → {:?}  (transfers so far: {})", queue.dequeue(), queue.transfers);
+    println!("  DEQUEUE → {:?}  (transfers so far: {})", queue.dequeue(), queue.transfers);
+    
+    println!("\nStill FIFO, even though both halves are LIFO:");
+    println!("  • Inbox reversed into outbox only when outbox is empty");
+    println!("  • Each item moves at most once → O(1) amortized");
+}
+
+fn demo_delay_queue() {
+    println!("\n{}", "=".repeat(60));
+    println!("DELAY QUEUE - DISCRETE EVENT SIMULATION");
+    println!("{}", "=".repeat(60));
+    
+    let mut events = DelayQueue::new();
+    events.schedule(30, "timeout");
+    events.schedule(10, "connect");
+    events.schedule(20, "send");
+    println!("\n  Scheduled: timeout@30, connect@10, send@20");
+    
+    for &now in &[5u64, 15, 30, 40] {
+        println!("  poll(now = {:>2}) → {:?}", now, events.poll(now));
+    }
+    
+    println!("\nFIFO by release time, not by insertion:");
+    println!("  • BinaryHeap keeps the earliest event on top");
+    println!("  • poll() drains everything that is due");
+}
+
+fn demo_mlfq_scheduler() {
+    println!("\n{}", "=".repeat(60));
+    println!("MULTI-LEVEL FEEDBACK QUEUE - PRIORITY BY BEHAVIOUR");
+    println!("{}", "=".repeat(60));
+    
+    // (name, time slices it needs)
+    let mut scheduler = MlfqScheduler::new(3);
+    for &task in &[("compile", 3), ("keypress", 1), ("render", 2)] {
+        scheduler.submit(task);
+    }
+    
+    println!("\n  3 levels; a task that needs more time drops one level");
+    while let Some((level, (name, remaining))) = scheduler.next() {
+        if remaining > 1 {
+            println!("  level {}: {:<9} ran a slice, demoted", level, name);
+            scheduler.requeue_lower(level, (name, remaining - 1));
+        } else {
+            println!("  level {}: {:<9} finished", level, name);
+        }
+    }
+    
+    println!("\nEach level is a plain FIFO; priority comes from which");
+    println!("queue is checked first");
+}
+
+fn demo_message_buffer() {
+    println!("\n{}", "=".repeat(60));
+    println!("MESSAGE BUFFER - OVERFLOW POLICIES");
+    println!("{}", "=".repeat(60));
+    
+    println!("\n  Capacity 2, sending m1, m2, m3:");
+    for &policy in &[OverflowPolicy::DropOldest, OverflowPolicy::DropNewest, OverflowPolicy::Reject] {
+        let mut buffer = MessageBuffer::new(2, policy);
+        let results: Vec<Result<(), String>> = ["m1", "m2", "m3"]
+            .iter()
+            .map(|msg| buffer.push(msg.to_string()))
+            .collect();
+        let mut delivered = Vec::new();
+        while let Some(msg) = buffer.pop() {
+            delivered.push(msg);
+        }
+        println!("  {:<10} send m3 → {:<12} delivered {:?}",
+                 format!("{:?}", policy), format!("{:?}", results[2]), delivered);
+    }
+    
+    println!("\nOnly Reject tells the sender - the others trade silent loss");
+    println!("of old or new data for never blocking");
+}
+
+fn demo_rate_limiter() {
+    println!("\n{}", "=".repeat(60));
+    println!("RATE LIMITING - A QUEUE OF TIMESTAMPS");
+    println!("{}", "=".repeat(60));
+    
+    let mut bucket = TokenBucket::new();
+    println!("\n  Limit: 3 requests per 10 ticks");
+    for &now in &[0u64, 1, 2, 3, 5, 11, 12, 13] {
+        let verdict = if bucket.allow(now, 10, 3) { "allowed" } else { "REJECTED" };
+        println!("  t = {:>2}: {}", now, verdict);
+    }
+    
+    println!("\nExpired timestamps leave from the FRONT - oldest first");
+}
+
+fn demo_moving_average() {
+    println!("\n{}", "=".repeat(60));
+    println!("MOVING AVERAGE - A SLIDING WINDOW QUEUE");
+    println!("{}", "=".repeat(60));
+    
+    let mut average = MovingAverage::new(3);
+    println!("\n  Window of 3:");
+    for &value in &[1.0, 10.0, 3.0, 5.0, 8.0] {
+        println!("  next({:>4}) → {:.2}", value, average.next(value));
+    }
+    
+    let mut precise = MovingAverage::new(2);
+    println!("\n  Window of 2, a huge value passing through:");
+    for &value in &[1e16, 1.0, 1.0] {
+        println!("  next({:>17}) → {}", value, precise.next(value));
+    }
+    
+    println!("\nO(1) per value: one in at the back, one out at the front");
+}
+
 fn demonstrate_ownership_with_collections() {
     println!("\n{}", "=".repeat(60));
     println!("OWNERSHIP WITH COLLECTIONS");
@@ -192,43 +843,1258 @@ fn demonstrate_pattern_matching() {
     }
 }
 
-// Practical example: Expression evaluation
-fn evaluate_rpn(expression: &str) -> Result<i32, String> {
-    let mut stack = Vec::new();
-    
-    for token in expression.split_whitespace() {
-        match token {
-            "+" => {
-                let b = stack.pop().ok_or("Stack underflow")?;
-                let a = stack.pop().ok_or("Stack underflow")?;
-                stack.push(a + b);
+// Errors from the stack-based expression evaluators
+#[derive(Debug, Clone, PartialEq)]
+enum RpnError {
+    StackUnderflow,
+    InvalidToken { token: String, index: usize },  // word index (char offset for infix)
+    TrailingValues(Vec<i32>),
+    TrailingCount(usize),
+    UnbalancedBrackets,
+    Overflow,
+    Io(String),
+    InvalidRegister(usize),
+    WrongArity { name: String, expected: usize, found: usize },
+    MissingOperand { index: usize },  // input ended where an operand belongs
+}
+
+impl fmt::Display for RpnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpnError::StackUnderflow => write!(f, "Stack underflow"),
+            RpnError::InvalidToken { ref token, index } => {
+                write!(f, "Invalid token {:?} at position {}", token, index)
+            }
+            RpnError::TrailingValues(ref values) if values.is_empty() => {
+                write!(f, "Invalid expression: no result")
+            }
+            RpnError::TrailingValues(ref values) => {
+                write!(f, "Invalid expression: values {:?} remain", values)
+            }
+            RpnError::TrailingCount(count) => {
+                write!(f, "Invalid expression: {} values remain", count)
             }
-            "-" => {
-                let b = stack.pop().ok_or("Stack underflow")?;
-                let a = stack.pop().ok_or("Stack underflow")?;
-                stack.push(a - b);
+            RpnError::UnbalancedBrackets => write!(f, "Unbalanced brackets"),
+            RpnError::Overflow => write!(f, "Arithmetic overflow"),
+            RpnError::Io(ref message) => write!(f, "I/O error: {}", message),
+            RpnError::InvalidRegister(register) => {
+                write!(f, "No register {} (machine has {})", register, REGISTER_COUNT)
             }
-            "*" => {
-                let b = stack.pop().ok_or("Stack underflow")?;
-                let a = stack.pop().ok_or("Stack underflow")?;
-                stack.push(a * b);
+            RpnError::WrongArity { ref name, expected, found } => {
+                write!(f, "{} expects {} arguments, found {}", name, expected, found)
             }
-            num => {
-                let value = num.parse()
-                    .map_err(|_| format!("Invalid number: {}", num))?;
-                stack.push(value);
+            RpnError::MissingOperand { index } => {
+                write!(f, "Expected an operand at position {}", index)
             }
         }
     }
-    
-    if stack.len() == 1 {
-        Ok(stack[0])
-    } else {
-        Err(format!("Invalid expression: {} values remain", stack.len()))
-    }
 }
 
-fn demo_rpn_calculator() {
+impl Error for RpnError {}
+
+impl<T> Stack<T> {
+    // Pops right (top) then left (second from top), pushes f(left, right).
+    // Underflow is reported before f runs.
+    fn apply_binary<F>(&mut self, f: F) -> Result<(), RpnError>
+    where
+        F: FnOnce(T, T) -> Result<T, RpnError>,
+    {
+        let b = self.pop().ok_or(RpnError::StackUnderflow)?;
+        let a = self.pop().ok_or(RpnError::StackUnderflow)?;
+        self.push(f(a, b)?);
+        Ok(())
+    }
+}
+
+// Practical example: Bracket matching with error positions
+struct BracketParser;
+
+impl BracketParser {
+    // Byte offset of the first mismatched closer, or of the innermost
+    // opener left unclosed at the end. None if the input is balanced.
+    fn first_error(input: &str) -> Option<usize> {
+        let mut openers = Stack::new();
+        
+        for (pos, ch) in input.char_indices() {
+            let expected = match ch {
+                '(' | '[' | '{' => {
+                    openers.push((pos, ch));
+                    continue;
+                }
+                ')' => '(',
+                ']' => '[',
+                '}' => '{',
+                _ => continue,
+            };
+            match openers.pop() {
+                Some((_, opener)) if opener == expected => {}
+                _ => return Some(pos),
+            }
+        }
+        
+        openers.pop().map(|(pos, _)| pos)
+    }
+}
+
+// Deepest bracket nesting, e.g. "{[()]}" → 3.
+// Err carries the same position BracketParser::first_error reports.
+fn max_nesting_depth(input: &str) -> Result<usize, usize> {
+    if let Some(pos) = BracketParser::first_error(input) {
+        return Err(pos);
+    }
+    
+    let mut open: Stack<char> = Stack::new();
+    let mut deepest = 0;
+    for ch in input.chars() {
+        match ch {
+            '(' | '[' | '{' => {
+                open.push(ch);
+                deepest = deepest.max(open.size());
+            }
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+    Ok(deepest)
+}
+
+// Same idea with named tags: <a>...</a> must nest, <br/> stands alone
+fn tags_balanced(input: &str) -> bool {
+    let mut open_tags: Stack<String> = Stack::new();
+    let mut rest = input;
+    
+    while let Some(start) = rest.find('<') {
+        let end = match rest[start..].find('>') {
+            Some(offset) => start + offset,
+            None => return false,  // unterminated tag
+        };
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+        
+        if tag.ends_with('/') {
+            continue;  // self-closing
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            match open_tags.pop() {
+                Some(ref open) if open == name.trim() => {}
+                _ => return false,
+            }
+        } else {
+            // Only the name matters, not attributes: <a href="..."> opens "a"
+            let name = tag.split_whitespace().next().unwrap_or("");
+            open_tags.push(name.to_string());
+        }
+    }
+    
+    open_tags.is_empty()
+}
+
+// Practical example: CSV fields. Quoting is a one-level nesting state:
+// inside quotes, commas are data and "" is an escaped quote.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' => in_quotes = true,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(ch),
+        }
+    }
+    fields.push(field);
+    
+    fields
+}
+
+// Replaces `from` with `to` only at depth 0, i.e. outside any (...).
+// Parentheses themselves are copied through; a stray ')' is ignored.
+fn replace_outside_brackets(input: &str, from: char, to: char) -> String {
+    let mut open: Stack<char> = Stack::new();
+    
+    input.chars()
+        .map(|ch| {
+            match ch {
+                '(' => open.push(ch),
+                ')' => {
+                    open.pop();
+                }
+                _ if ch == from && open.is_empty() => return to,
+                _ => {}
+            }
+            ch
+        })
+        .collect()
+}
+
+// Practical example: Monotonic stack
+// For each element, the next greater element to its right (-1 if none).
+// The stack holds indices still waiting for their answer.
+fn next_greater(nums: &[i32]) -> Vec<i32> {
+    let mut result = vec![-1; nums.len()];
+    let mut waiting: Stack<usize> = Stack::new();
+    
+    for (i, &num) in nums.iter().enumerate() {
+        while let Some(&top) = waiting.peek() {
+            if nums[top] >= num {
+                break;
+            }
+            result[top] = num;
+            waiting.pop();
+        }
+        waiting.push(i);
+    }
+    
+    result
+}
+
+// Largest rectangle in a histogram. The stack keeps bar indices with
+// increasing heights; popping a bar means its rectangle can't extend
+// further right, and the new top bounds it on the left.
+fn largest_rectangle(heights: &[u32]) -> u64 {
+    let mut rising: Stack<usize> = Stack::new();
+    let mut best = 0u64;
+    
+    // A final zero-height bar flushes everything left on the stack
+    for i in 0..=heights.len() {
+        let height = heights.get(i).copied().unwrap_or(0);
+        while let Some(&top) = rising.peek() {
+            if heights[top] <= height {
+                break;
+            }
+            rising.pop();
+            let left = rising.peek().map_or(0, |&l| l + 1);
+            let area = heights[top] as u64 * (i - left) as u64;
+            best = best.max(area);
+        }
+        rising.push(i);
+    }
+    
+    best
+}
+
+// Backtracking without recursion: can nums be split into two groups
+// with equal sums? Each frame (index, sum) is a decision point - item
+// `index` is either left out or added to the running sum. Frames already
+// explored are skipped.
+fn can_partition_equal_sum(nums: &[i32]) -> bool {
+    let total: i64 = nums.iter().map(|&n| n as i64).sum();
+    if total % 2 != 0 {
+        return false;
+    }
+    let target = total / 2;
+    
+    let mut frames: Stack<(usize, i64)> = Stack::new();
+    let mut seen = HashSet::new();
+    frames.push((0, 0));
+    
+    while let Some((index, sum)) = frames.pop() {
+        if sum == target {
+            return true;
+        }
+        if index == nums.len() || !seen.insert((index, sum)) {
+            continue;
+        }
+        frames.push((index + 1, sum));
+        frames.push((index + 1, sum + nums[index] as i64));
+    }
+    
+    false
+}
+
+// Practical example: Breadth-first search
+// Shortest hop count from start to every node, None if unreachable
+fn bfs_distances(adjacency: &[Vec<usize>], start: usize) -> Vec<Option<usize>> {
+    let mut distances = vec![None; adjacency.len()];
+    if start >= adjacency.len() {
+        return distances;
+    }
+    
+    let mut queue = Queue::new();
+    distances[start] = Some(0);
+    queue.enqueue(start);
+    
+    while let Some(node) = queue.dequeue() {
+        let next = distances[node].map(|d| d + 1);
+        for &neighbor in &adjacency[node] {
+            if distances[neighbor].is_none() {
+                distances[neighbor] = next;
+                queue.enqueue(neighbor);
+            }
+        }
+    }
+    
+    distances
+}
+
+// Two-colors each component by BFS; a neighbor with the same color
+// as its node means an odd cycle. Restarts from every uncolored node
+// so disconnected graphs are covered.
+fn is_bipartite(adjacency: &[Vec<usize>]) -> bool {
+    let mut colors: Vec<Option<bool>> = vec![None; adjacency.len()];
+    
+    for start in 0..adjacency.len() {
+        if colors[start].is_some() {
+            continue;
+        }
+        let mut queue: Queue<usize> = Queue::new();
+        colors[start] = Some(false);
+        queue.enqueue(start);
+        
+        while let Some(node) = queue.dequeue() {
+            let color = colors[node] == Some(true);
+            for &neighbor in &adjacency[node] {
+                match colors[neighbor] {
+                    None => {
+                        colors[neighbor] = Some(!color);
+                        queue.enqueue(neighbor);
+                    }
+                    Some(other) if other == color => return false,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    
+    true
+}
+
+// BFS on a grid: recolor the region connected to start (4-neighbors)
+fn flood_fill(grid: &mut [Vec<u8>], start: (usize, usize), new: u8) {
+    let (row, col) = start;
+    let original = match grid.get(row).and_then(|r| r.get(col)) {
+        Some(&color) => color,
+        None => return,
+    };
+    if original == new {
+        return;  // nothing to do, and recoloring would never "mark" cells
+    }
+    
+    let mut queue = Queue::new();
+    grid[row][col] = new;
+    queue.enqueue(start);
+    
+    while let Some((r, c)) = queue.dequeue() {
+        let neighbors = [
+            (r.wrapping_sub(1), c),
+            (r + 1, c),
+            (r, c.wrapping_sub(1)),
+            (r, c + 1),
+        ];
+        for &(nr, nc) in &neighbors {
+            // wrapping_sub turns -1 into usize::MAX, which get() rejects
+            if grid.get(nr).and_then(|r| r.get(nc)) == Some(&original) {
+                grid[nr][nc] = new;
+                queue.enqueue((nr, nc));
+            }
+        }
+    }
+}
+
+// A plain binary tree for the traversal examples
+struct BinaryTree<T> {
+    root: Option<Box<TreeNode<T>>>,
+}
+
+struct TreeNode<T> {
+    value: T,
+    left: Option<Box<TreeNode<T>>>,
+    right: Option<Box<TreeNode<T>>>,
+}
+
+impl<T> TreeNode<T> {
+    fn new(value: T, left: Option<TreeNode<T>>, right: Option<TreeNode<T>>) -> Self {
+        TreeNode { value, left: left.map(Box::new), right: right.map(Box::new) }
+    }
+    
+    fn leaf(value: T) -> Self {
+        TreeNode::new(value, None, None)
+    }
+}
+
+// Lazy pre-order walk (node, left subtree, right subtree) without
+// recursion: the stack holds subtrees still to visit, right pushed
+// before left so the left one pops first
+struct DfsIter<'a, T: 'a> {
+    pending: Stack<&'a TreeNode<T>>,
+}
+
+impl<T> BinaryTree<T> {
+    fn dfs_iter(&self) -> DfsIter<'_, T> {
+        let mut pending = Stack::new();
+        if let Some(ref root) = self.root {
+            pending.push(&**root);
+        }
+        DfsIter { pending }
+    }
+}
+
+impl<'a, T> Iterator for DfsIter<'a, T> {
+    type Item = &'a T;
+    
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.pending.pop()?;
+        if let Some(ref right) = node.right {
+            self.pending.push(right);
+        }
+        if let Some(ref left) = node.left {
+            self.pending.push(left);
+        }
+        Some(&node.value)
+    }
+}
+
+// Breadth-first over a tree: values grouped by depth, root level first
+fn level_order<T: Clone>(tree: &BinaryTree<T>) -> Vec<Vec<T>> {
+    let mut levels: Vec<Vec<T>> = Vec::new();
+    let mut queue = Queue::new();
+    if let Some(ref root) = tree.root {
+        queue.enqueue((root, 0));
+    }
+    
+    while let Some((node, depth)) = queue.dequeue() {
+        if depth == levels.len() {
+            levels.push(Vec::new());
+        }
+        levels[depth].push(node.value.clone());
+        for child in node.left.iter().chain(node.right.iter()) {
+            queue.enqueue((child, depth + 1));
+        }
+    }
+    
+    levels
+}
+
+// A single lexical unit of an RPN expression
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(i32),
+    Op(char),
+    Dup,   // duplicate the top
+    Swap,  // exchange the top two
+    Neg,   // negate the top
+    Open,  // [ starts a sub-expression on a fresh stack
+    Close, // ] ends it, pushing its single result
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, RpnError> {
+    expression
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, word)| parse_token(word, index))
+        .collect()
+}
+
+// Writes a token back as the word that produces it
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Num(value) => write!(f, "{}", value),
+            Token::Op(op) => write!(f, "{}", op),
+            Token::Dup => write!(f, "dup"),
+            Token::Swap => write!(f, "swap"),
+            Token::Neg => write!(f, "neg"),
+            Token::Open => write!(f, "["),
+            Token::Close => write!(f, "]"),
+        }
+    }
+}
+
+// One word of input; index is its position, for error reporting
+fn parse_token(word: &str, index: usize) -> Result<Token, RpnError> {
+    match word {
+        "+" => Ok(Token::Op('+')),
+        "-" => Ok(Token::Op('-')),
+        "*" => Ok(Token::Op('*')),
+        "dup" => Ok(Token::Dup),
+        "swap" => Ok(Token::Swap),
+        "neg" => Ok(Token::Neg),
+        "[" => Ok(Token::Open),
+        "]" => Ok(Token::Close),
+        num => num.parse()
+            .map(Token::Num)
+            .map_err(|_| RpnError::InvalidToken { token: num.to_string(), index }),
+    }
+}
+
+// Integer arithmetic modes for the evaluator
+fn checked_op(op: char, a: i32, b: i32) -> Result<i32, RpnError> {
+    match op {
+        '+' => a.checked_add(b),
+        '-' => a.checked_sub(b),
+        _ => a.checked_mul(b),
+    }
+    .ok_or(RpnError::Overflow)
+}
+
+fn saturating_op(op: char, a: i32, b: i32) -> Result<i32, RpnError> {
+    Ok(match op {
+        '+' => a.saturating_add(b),
+        '-' => a.saturating_sub(b),
+        _ => a.saturating_mul(b),
+    })
+}
+
+// Parsed once, evaluated as many times as needed
+#[derive(Debug, Clone, PartialEq)]
+struct RpnExpr {
+    tokens: Vec<Token>,
+}
+
+impl FromStr for RpnExpr {
+    type Err = RpnError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(RpnExpr { tokens: tokenize(s)? })
+    }
+}
+
+impl RpnExpr {
+    fn eval(&self) -> Result<i32, RpnError> {
+        self.eval_with_depth().map(|(result, _)| result)
+    }
+    
+    // Also reports the deepest the stack got - the expression's working memory
+    fn eval_with_depth(&self) -> Result<(i32, usize), RpnError> {
+        self.eval_with(checked_op)
+    }
+    
+    // Clamps to i32::MIN / i32::MAX instead of reporting overflow
+    fn eval_saturating(&self) -> Result<i32, RpnError> {
+        self.eval_with(saturating_op).map(|(result, _)| result)
+    }
+    
+    // Depth counts values on every level of [ ] nesting
+    fn eval_with(&self, apply: fn(char, i32, i32) -> Result<i32, RpnError>)
+        -> Result<(i32, usize), RpnError>
+    {
+        let mut stack = EvalStack::new();
+        let mut max_depth = 0;
+        
+        for token in &self.tokens {
+            stack.apply_token(token, apply)?;
+            max_depth = max_depth.max(stack.depth());
+        }
+        
+        stack.finish().map(|result| (result, max_depth))
+    }
+}
+
+// Working state shared by the evaluators. `current` is the innermost
+// group being evaluated. "[ 3 4 + ] 2 *" → 14: '[' parks the current
+// stack on a stack of stacks, ']' reduces the group to one value and
+// pushes it onto the stack it interrupted.
+struct EvalStack {
+    current: Stack<i32>,
+    groups: Stack<Stack<i32>>,
+    parked: usize,  // values waiting in outer groups
+}
+
+impl EvalStack {
+    fn new() -> Self {
+        EvalStack { current: Stack::new(), groups: Stack::new(), parked: 0 }
+    }
+    
+    // Values held across all levels
+    fn depth(&self) -> usize {
+        self.parked + self.current.size()
+    }
+    
+    // Executes one token
+    fn apply_token(&mut self, token: &Token,
+                   apply: fn(char, i32, i32) -> Result<i32, RpnError>)
+        -> Result<(), RpnError>
+    {
+        let stack = &mut self.current;
+        match *token {
+            Token::Num(value) => stack.push(value),
+            Token::Op(op) => stack.apply_binary(|a, b| apply(op, a, b))?,
+            Token::Dup => {
+                let top = *stack.peek().ok_or(RpnError::StackUnderflow)?;
+                stack.push(top);
+            }
+            Token::Swap => {
+                let b = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                let a = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                stack.push(b);
+                stack.push(a);
+            }
+            // 0 - top, so negating i32::MIN follows the arithmetic mode too
+            Token::Neg => {
+                let top = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                stack.push(apply('-', 0, top)?);
+            }
+            Token::Open => {
+                self.parked += stack.size();
+                let outer = std::mem::replace(stack, Stack::new());
+                self.groups.push(outer);
+            }
+            Token::Close => {
+                let outer = self.groups.pop().ok_or(RpnError::UnbalancedBrackets)?;
+                let result = single_result(std::mem::replace(stack, outer))?;
+                self.parked -= stack.size();
+                stack.push(result);
+            }
+        }
+        Ok(())
+    }
+    
+    // The single value left once every group is closed
+    fn finish(self) -> Result<i32, RpnError> {
+        if !self.groups.is_empty() {
+            return Err(RpnError::UnbalancedBrackets);
+        }
+        single_result(self.current)
+    }
+}
+
+// A finished evaluation must leave exactly one value. Anything else is
+// reported with the leftover values, bottom-to-top.
+fn single_result(mut stack: Stack<i32>) -> Result<i32, RpnError> {
+    if stack.size() == 1 {
+        stack.pop().ok_or(RpnError::StackUnderflow)
+    } else {
+        Err(RpnError::TrailingValues(stack.into_vec()))
+    }
+}
+
+// Pops the top n tokens if they are all literals, returning their
+// values bottom-to-top; otherwise leaves the stack as it was
+fn pop_literals(stack: &mut Stack<Token>, n: usize) -> Option<Vec<i32>> {
+    let mut popped = Vec::with_capacity(n);
+    while popped.len() < n {
+        match stack.pop() {
+            Some(token) => popped.push(token),
+            None => break,
+        }
+    }
+    
+    let mut values: Vec<i32> = popped.iter()
+        .filter_map(|token| match *token {
+            Token::Num(value) => Some(value),
+            _ => None,
+        })
+        .collect();
+    if values.len() == n {
+        values.reverse();
+        return Some(values);
+    }
+    while let Some(token) = popped.pop() {
+        stack.push(token);
+    }
+    None
+}
+
+// Peephole constant folding: the output is kept on a token stack, and an
+// operator whose operands are literals on top of it is replaced by its
+// result. Folds cascade - in "2 3 + 4 *", "2 3 +" is already 5 by the
+// time "4 *" arrives, so it goes 5 4 * → 20. Anything that can't be
+// folded (a group boundary, a missing operand) is left for eval.
+fn simplify_rpn(expression: &str) -> Result<String, RpnError> {
+    let mut folded: Stack<Token> = Stack::new();
+    
+    for token in tokenize(expression)? {
+        let literals = match token {
+            Token::Op(_) | Token::Swap => pop_literals(&mut folded, 2),
+            Token::Dup | Token::Neg => pop_literals(&mut folded, 1),
+            _ => None,
+        };
+        match (token, literals) {
+            (Token::Op(op), Some(v)) => folded.push(Token::Num(checked_op(op, v[0], v[1])?)),
+            (Token::Swap, Some(v)) => {
+                folded.push(Token::Num(v[1]));
+                folded.push(Token::Num(v[0]));
+            }
+            (Token::Dup, Some(v)) => {
+                folded.push(Token::Num(v[0]));
+                folded.push(Token::Num(v[0]));
+            }
+            (Token::Neg, Some(v)) => {
+                folded.push(Token::Num(v[0].checked_neg().ok_or(RpnError::Overflow)?));
+            }
+            (token, _) => folded.push(token),
+        }
+    }
+    
+    let words: Vec<String> = folded.into_vec().iter().map(|token| token.to_string()).collect();
+    Ok(words.join(" "))
+}
+
+// Practical example: Expression evaluation
+fn evaluate_rpn(expression: &str) -> Result<i32, RpnError> {
+    expression.parse::<RpnExpr>()?.eval()
+}
+
+fn evaluate_rpn_with_depth(expression: &str) -> Result<(i32, usize), RpnError> {
+    expression.parse::<RpnExpr>()?.eval_with_depth()
+}
+
+fn evaluate_rpn_saturating(expression: &str) -> Result<i32, RpnError> {
+    expression.parse::<RpnExpr>()?.eval_saturating()
+}
+
+// User-defined words: each word found in defs is replaced by its tokens
+// before evaluation, e.g. "sq" → "dup *". Definitions don't nest.
+fn evaluate_rpn_with_defs(expression: &str, defs: &HashMap<String, Vec<Token>>)
+    -> Result<i32, RpnError>
+{
+    let mut tokens = Vec::new();
+    for (index, word) in expression.split_whitespace().enumerate() {
+        match defs.get(word) {
+            Some(body) => tokens.extend(body.iter().cloned()),
+            None => tokens.push(parse_token(word, index)?),
+        }
+    }
+    RpnExpr { tokens }.eval()
+}
+
+// A named function callable from an expression. args are in the order
+// they were pushed: "15 0 10 clamp" calls apply(&[15, 0, 10]).
+struct RpnFunction {
+    arity: usize,
+    apply: fn(&[i32]) -> Result<i32, RpnError>,
+}
+
+fn evaluate_rpn_with_functions(expression: &str, functions: &HashMap<String, RpnFunction>)
+    -> Result<i32, RpnError>
+{
+    let mut eval = EvalStack::new();
+    
+    for (index, word) in expression.split_whitespace().enumerate() {
+        let stack = &mut eval.current;
+        match functions.get(word) {
+            Some(function) => {
+                if stack.size() < function.arity {
+                    return Err(RpnError::WrongArity {
+                        name: word.to_string(),
+                        expected: function.arity,
+                        found: stack.size(),
+                    });
+                }
+                let mut args = Vec::with_capacity(function.arity);
+                while args.len() < function.arity {
+                    args.extend(stack.pop());
+                }
+                args.reverse();
+                stack.push((function.apply)(&args)?);
+            }
+            None => eval.apply_token(&parse_token(word, index)?, checked_op)?,
+        }
+    }
+    
+    eval.finish()
+}
+
+// Streams tokens line by line - only one line is in memory at a time.
+// Token positions count across lines.
+fn evaluate_rpn_from_reader<R: Read>(reader: R) -> Result<i32, RpnError> {
+    let mut stack = EvalStack::new();
+    let mut index = 0;
+    
+    for line in BufReader::new(reader).lines() {
+        let line = line.map_err(|e| RpnError::Io(e.to_string()))?;
+        for word in line.split_whitespace() {
+            stack.apply_token(&parse_token(word, index)?, checked_op)?;
+            index += 1;
+        }
+    }
+    
+    stack.finish()
+}
+
+// Operator associativity for the shunting-yard converter
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Assoc {
+    Left,
+    Right,
+}
+
+// Unary minus on the operator stack. It outranks every binary operator
+// and, being prefix, never pops anything when pushed. Emitted as "neg".
+const UNARY_MINUS: char = '~';
+
+fn operator_word(op: char) -> String {
+    if op == UNARY_MINUS { "neg".to_string() } else { op.to_string() }
+}
+
+// Dijkstra's shunting-yard: infix → RPN using an operator stack.
+// prec maps each operator to (precedence, associativity); higher binds tighter.
+// A '-' where an operand is expected (at the start, after an operator or
+// after '(') is unary: "2 * -3" → "2 3 neg *". It must be followed by a
+// number or '(' - "-" and "- - 3" are errors, "-(-3)" is fine.
+// Operands and operators must alternate: "3 + * 4", "3 4" and "()" are
+// rejected here rather than left for the evaluator to trip over.
+fn infix_to_rpn_with(expr: &str, prec: &HashMap<char, (u8, Assoc)>)
+    -> Result<String, RpnError>
+{
+    let mut output: Vec<String> = Vec::new();
+    let mut operators: Stack<char> = Stack::new();
+    let mut chars = expr.chars().enumerate().peekable();
+    let mut expect_operand = true;
+    let mut after_unary = false;
+    
+    while let Some((offset, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+        if ch == '-' && expect_operand && !after_unary {
+            operators.push(UNARY_MINUS);
+            after_unary = true;
+            continue;
+        }
+        after_unary = false;
+        
+        let mut token = ch.to_string();
+        if ch.is_ascii_digit() {
+            while let Some(&(_, digit)) = chars.peek() {
+                if !digit.is_ascii_digit() {
+                    break;
+                }
+                token.push(digit);
+                chars.next();
+            }
+        }
+        
+        // Numbers and '(' start an operand; ')' and binary operators follow one
+        let starts_operand = ch.is_ascii_digit() || ch == '(';
+        let follows_operand = ch == ')' || prec.contains_key(&ch);
+        if (starts_operand && !expect_operand) || (follows_operand && expect_operand) {
+            return Err(RpnError::InvalidToken { token, index: offset });
+        }
+        
+        if ch.is_ascii_digit() {
+            output.push(token);
+            expect_operand = false;
+        } else if ch == '(' {
+            operators.push(ch);
+        } else if ch == ')' {
+            loop {
+                match operators.pop() {
+                    Some('(') => break,
+                    Some(op) => output.push(operator_word(op)),
+                    None => return Err(RpnError::UnbalancedBrackets),
+                }
+            }
+        } else if let Some(&(p1, assoc)) = prec.get(&ch) {
+            expect_operand = true;
+            // Pop operators that must be applied before this one
+            while let Some(&top) = operators.peek() {
+                let p2 = match prec.get(&top) {
+                    _ if top == UNARY_MINUS => u8::MAX,
+                    Some(&(p2, _)) => p2,
+                    None => break,  // '('
+                };
+                if p2 > p1 || (p2 == p1 && assoc == Assoc::Left) {
+                    output.push(operator_word(top));
+                    operators.pop();
+                } else {
+                    break;
+                }
+            }
+            operators.push(ch);
+        } else {
+            return Err(RpnError::InvalidToken { token: ch.to_string(), index: offset });
+        }
+    }
+    
+    if expect_operand {
+        return Err(RpnError::MissingOperand { index: expr.chars().count() });
+    }
+    while let Some(op) = operators.pop() {
+        if op == '(' {
+            return Err(RpnError::UnbalancedBrackets);
+        }
+        output.push(operator_word(op));
+    }
+    
+    Ok(output.join(" "))
+}
+
+fn default_precedence() -> HashMap<char, (u8, Assoc)> {
+    let mut prec = HashMap::new();
+    prec.insert('+', (1, Assoc::Left));
+    prec.insert('-', (1, Assoc::Left));
+    prec.insert('*', (2, Assoc::Left));
+    prec
+}
+
+fn infix_to_rpn(expr: &str) -> Result<String, RpnError> {
+    infix_to_rpn_with(expr, &default_precedence())
+}
+
+// Expression tree built from RPN: each operator pops its operand
+// subtrees off a Stack<Expr> and pushes the combined tree
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Num(f64),
+    Var(String),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, f64),  // constant exponent only
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Expr::Num(value) => write!(f, "{}", value),
+            Expr::Var(ref name) => write!(f, "{}", name),
+            Expr::Add(ref a, ref b) => write!(f, "({} + {})", a, b),
+            Expr::Sub(ref a, ref b) => write!(f, "({} - {})", a, b),
+            Expr::Mul(ref a, ref b) => write!(f, "({} * {})", a, b),
+            Expr::Pow(ref base, exponent) => write!(f, "{}^{}", base, exponent),
+        }
+    }
+}
+
+// Plain decimal literals only: optional '-', digits, at most one '.'.
+// Stricter than f64::from_str, which also takes "1e5", "+3" and "inf".
+fn parse_decimal(token: &str, index: usize) -> Result<f64, RpnError> {
+    let digits = token.strip_prefix('-').unwrap_or(token);
+    let points = digits.matches('.').count();
+    let well_formed = points <= 1
+        && digits.chars().any(|c| c.is_ascii_digit())
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.');
+    
+    let invalid = || RpnError::InvalidToken { token: token.to_string(), index };
+    if well_formed {
+        token.parse().map_err(|_| invalid())
+    } else {
+        Err(invalid())
+    }
+}
+
+// "x x *" → Mul(x, x). Words starting with a letter are variables;
+// the right operand of ^ must be a number.
+fn parse_expr(expression: &str) -> Result<Expr, RpnError> {
+    let mut stack: Stack<Expr> = Stack::new();
+    
+    for (index, token) in expression.split_whitespace().enumerate() {
+        match token {
+            "+" | "-" | "*" => {
+                let b = Box::new(stack.pop().ok_or(RpnError::StackUnderflow)?);
+                let a = Box::new(stack.pop().ok_or(RpnError::StackUnderflow)?);
+                stack.push(match token {
+                    "+" => Expr::Add(a, b),
+                    "-" => Expr::Sub(a, b),
+                    _ => Expr::Mul(a, b),
+                });
+            }
+            "^" => {
+                let exponent = match stack.pop() {
+                    Some(Expr::Num(n)) => n,
+                    Some(other) => {
+                        return Err(RpnError::InvalidToken { token: other.to_string(), index });
+                    }
+                    None => return Err(RpnError::StackUnderflow),
+                };
+                let base = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                stack.push(Expr::Pow(Box::new(base), exponent));
+            }
+            word if word.starts_with(|c: char| c.is_alphabetic()) => {
+                stack.push(Expr::Var(word.to_string()));
+            }
+            num => stack.push(Expr::Num(parse_decimal(num, index)?)),
+        }
+    }
+    
+    match stack.size() {
+        1 => stack.pop().ok_or(RpnError::StackUnderflow),
+        count => Err(RpnError::TrailingCount(count)),
+    }
+}
+
+impl Expr {
+    // Evaluates with `var` bound to `value`; any other variable is NaN
+    fn eval(&self, var: &str, value: f64) -> f64 {
+        match *self {
+            Expr::Num(n) => n,
+            Expr::Var(ref name) => if name == var { value } else { f64::NAN },
+            Expr::Add(ref a, ref b) => a.eval(var, value) + b.eval(var, value),
+            Expr::Sub(ref a, ref b) => a.eval(var, value) - b.eval(var, value),
+            Expr::Mul(ref a, ref b) => a.eval(var, value) * b.eval(var, value),
+            Expr::Pow(ref base, exponent) => base.eval(var, value).powf(exponent),
+        }
+    }
+}
+
+// Symbolic d/d(var): sum, product and power rules, no simplification
+fn differentiate(expr: &Expr, var: &str) -> Expr {
+    match *expr {
+        Expr::Num(_) => Expr::Num(0.0),
+        Expr::Var(ref name) => Expr::Num(if name == var { 1.0 } else { 0.0 }),
+        Expr::Add(ref a, ref b) => Expr::Add(
+            Box::new(differentiate(a, var)),
+            Box::new(differentiate(b, var)),
+        ),
+        Expr::Sub(ref a, ref b) => Expr::Sub(
+            Box::new(differentiate(a, var)),
+            Box::new(differentiate(b, var)),
+        ),
+        // (uv)' = u'v + uv'
+        Expr::Mul(ref a, ref b) => Expr::Add(
+            Box::new(Expr::Mul(Box::new(differentiate(a, var)), b.clone())),
+            Box::new(Expr::Mul(a.clone(), Box::new(differentiate(b, var)))),
+        ),
+        // (u^n)' = n · u^(n-1) · u'
+        Expr::Pow(ref base, exponent) => Expr::Mul(
+            Box::new(Expr::Mul(
+                Box::new(Expr::Num(exponent)),
+                Box::new(Expr::Pow(base.clone(), exponent - 1.0)),
+            )),
+            Box::new(differentiate(base, var)),
+        ),
+    }
+}
+
+// Instruction set for the RpnMachine, independent of any text syntax
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Push(i32),
+    Add,
+    Sub,
+    Mul,
+    Dup,
+    Swap,
+    Over,              // copy the second-from-top onto the top
+    Jump(usize),       // continue at this op index
+    JumpIfZero(usize), // pop; jump if the popped value was 0
+    Store(usize),      // pop into register N
+    Load(usize),       // push a copy of register N
+}
+
+const REGISTER_COUNT: usize = 8;
+const UNDO_LIMIT: usize = 64;
+
+// A tiny stack machine. The stack and registers persist between runs.
+// Before each op the machine saves a snapshot (the newest UNDO_LIMIT are
+// kept), so undo() can step back one op at a time.
+struct RpnMachine {
+    stack: Vec<i32>,
+    registers: [i32; REGISTER_COUNT],
+    history: VecDeque<(Vec<i32>, [i32; REGISTER_COUNT])>,
+}
+
+impl RpnMachine {
+    fn new() -> Self {
+        RpnMachine {
+            stack: Vec::new(),
+            registers: [0; REGISTER_COUNT],
+            history: VecDeque::new(),
+        }
+    }
+    
+    // Current working stack, bottom-to-top, for rendering in a UI
+    fn peek_stack(&self) -> &[i32] {
+        &self.stack
+    }
+    
+    // Restores the state from before the most recent op.
+    // Returns false when there is no history left.
+    fn undo(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some((stack, registers)) => {
+                self.stack = stack;
+                self.registers = registers;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    fn pop(&mut self) -> Result<i32, RpnError> {
+        self.stack.pop().ok_or(RpnError::StackUnderflow)
+    }
+    
+    // Executes from op 0 until the program counter runs off the end,
+    // then returns the value left on top. Jumps make loops possible,
+    // so a badly written program may never halt. A run either succeeds
+    // or changes nothing: on Err the stack, registers and undo history
+    // are all put back as they were before the call.
+    fn run(&mut self, program: &[Op]) -> Result<i32, RpnError> {
+        let before = (self.stack.clone(), self.registers, self.history.clone());
+        
+        let result = self.execute(program);
+        if result.is_err() {
+            let (stack, registers, history) = before;
+            self.stack = stack;
+            self.registers = registers;
+            self.history = history;
+        }
+        result
+    }
+    
+    // Every op is snapshotted for undo; the oldest snapshot is evicted
+    // only once the op has succeeded
+    fn execute(&mut self, program: &[Op]) -> Result<i32, RpnError> {
+        let mut pc = 0;
+        
+        while let Some(&op) = program.get(pc) {
+            pc += 1;
+            let snapshot = (self.stack.clone(), self.registers);
+            self.step(op, &mut pc)?;
+            if self.history.len() == UNDO_LIMIT {
+                self.history.pop_front();
+            }
+            self.history.push_back(snapshot);
+        }
+        
+        self.stack.last().copied().ok_or(RpnError::StackUnderflow)
+    }
+    
+    // One instruction; pc already points past it and jumps overwrite it
+    fn step(&mut self, op: Op, pc: &mut usize) -> Result<(), RpnError> {
+        match op {
+            Op::Push(value) => self.stack.push(value),
+            Op::Add | Op::Sub | Op::Mul => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                let symbol = match op {
+                    Op::Add => '+',
+                    Op::Sub => '-',
+                    _ => '*',
+                };
+                self.stack.push(checked_op(symbol, a, b)?);
+            }
+            Op::Dup => {
+                let a = self.pop()?;
+                self.stack.push(a);
+                self.stack.push(a);
+            }
+            Op::Swap => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                self.stack.push(b);
+                self.stack.push(a);
+            }
+            Op::Over => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                self.stack.push(a);
+                self.stack.push(b);
+                self.stack.push(a);
+            }
+            Op::Jump(target) => *pc = target,
+            Op::JumpIfZero(target) => {
+                if self.pop()? == 0 {
+                    *pc = target;
+                }
+            }
+            Op::Store(register) => {
+                let value = self.pop()?;
+                *self.registers.get_mut(register)
+                    .ok_or(RpnError::InvalidRegister(register))? = value;
+            }
+            Op::Load(register) => {
+                let value = *self.registers.get(register)
+                    .ok_or(RpnError::InvalidRegister(register))?;
+                self.stack.push(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Runs a program on a fresh machine
+fn evaluate_program(ops: &[Op]) -> Result<i32, RpnError> {
+    RpnMachine::new().run(ops)
+}
+
+// Checks an expression by tracking only the stack DEPTH - no values computed
+fn validate_rpn(expression: &str) -> Result<(), RpnError> {
+    // Same errors as single_result: an empty stack is "no result"
+    let leftover = |depth: usize| if depth == 0 {
+        RpnError::TrailingValues(Vec::new())
+    } else {
+        RpnError::TrailingCount(depth)
+    };
+    let mut depth = 0usize;
+    let mut outer_depths: Stack<usize> = Stack::new();
+    
+    for token in tokenize(expression)? {
+        // (operands needed, net change in depth)
+        let (needs, effect) = match token {
+            Token::Num(_) => (0, 1),
+            Token::Op(_) => (2, -1),
+            Token::Dup => (1, 1),
+            Token::Swap => (2, 0),
+            Token::Neg => (1, 0),
+            Token::Open => {
+                outer_depths.push(depth);
+                depth = 0;
+                continue;
+            }
+            Token::Close => {
+                let outer = outer_depths.pop().ok_or(RpnError::UnbalancedBrackets)?;
+                if depth != 1 {
+                    return Err(leftover(depth));
+                }
+                depth = outer + 1;
+                continue;
+            }
+        };
+        if depth < needs {
+            return Err(RpnError::StackUnderflow);
+        }
+        depth = (depth as isize + effect) as usize;
+    }
+    
+    if !outer_depths.is_empty() {
+        Err(RpnError::UnbalancedBrackets)
+    } else if depth == 1 {
+        Ok(())
+    } else {
+        Err(leftover(depth))
+    }
+}
+
+// Same stack-pop pattern, but over booleans
+fn eval_bool_postfix(expression: &str) -> Result<bool, RpnError> {
+    let mut stack = Stack::new();
+    
+    for (index, token) in expression.split_whitespace().enumerate() {
+        match token {
+            "true" => stack.push(true),
+            "false" => stack.push(false),
+            "not" => {
+                let a = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                stack.push(!a);
+            }
+            "and" | "or" | "xor" => {
+                let b = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                let a = stack.pop().ok_or(RpnError::StackUnderflow)?;
+                stack.push(match token {
+                    "and" => a && b,
+                    "or" => a || b,
+                    _ => a ^ b,
+                });
+            }
+            other => {
+                return Err(RpnError::InvalidToken { token: other.to_string(), index });
+            }
+        }
+    }
+    
+    match stack.size() {
+        1 => stack.pop().ok_or(RpnError::StackUnderflow),
+        0 => Err(RpnError::StackUnderflow),
+        count => Err(RpnError::TrailingCount(count)),
+    }
+}
+
+fn demo_rpn_calculator() {
     println!("\n{}", "=".repeat(60));
     println!("PRACTICAL EXAMPLE: RPN CALCULATOR");
     println!("{}", "=".repeat(60));
@@ -237,6 +2103,7 @@ fn demo_rpn_calculator() {
         "3 4 +",           // 7
         "15 7 1 1 + - *",  // 75
         "5 3 * 2 +",       // 17
+        "1 2 3",           // error: three values left over
     ];
     
     for expr in expressions {
@@ -246,12 +2113,474 @@ fn demo_rpn_calculator() {
         }
     }
     
+    println!("\nParse once, evaluate many times:");
+    match "3 4 +".parse::<RpnExpr>() {
+        Ok(parsed) => {
+            println!("  Parsed: {:?}", parsed.tokens);
+            for _ in 0..2 {
+                println!("  eval() = {:?}", parsed.eval());
+            }
+        }
+        Err(e) => println!("  Parse error: {}", e),
+    }
+    match "3 foo +".parse::<RpnExpr>() {
+        Ok(parsed) => println!("  Parsed: {:?}", parsed.tokens),
+        Err(e) => println!("  \"3 foo +\" → Parse error: {}", e),
+    }
+    
+    println!("\nWorking memory (peak stack depth):");
+    for expr in &["3 4 +", "15 7 1 1 + - *", "1 2 + 3 + 4 +"] {
+        if let Ok((result, depth)) = evaluate_rpn_with_depth(expr) {
+            println!("  {:<16} = {:<4} peak depth {}", expr, result, depth);
+        }
+    }
+    
+    println!("\nOverflow: checked vs saturating:");
+    for expr in &["2147483647 1 +", "-2147483648 2 *", "-2147483648 neg", "5 3 * 2 +"] {
+        println!("  {:<18} checked = {:?}", expr, evaluate_rpn(expr));
+        println!("  {:<18} saturating = {:?}", "", evaluate_rpn_saturating(expr));
+    }
+    
+    println!("\nReading from any io::Read (file, pipe, buffer):");
+    let single_line = Cursor::new("3 4 +".as_bytes());
+    println!("  \"3 4 +\" → {:?}", evaluate_rpn_from_reader(single_line));
+    let multi_line = Cursor::new("15 7\n1 1 +\n- *\n".as_bytes());
+    println!("  \"15 7⏎1 1 +⏎- *\" → {:?}", evaluate_rpn_from_reader(multi_line));
+    let grouped = Cursor::new("[ 3 4\n+ ] 2 *".as_bytes());
+    println!("  \"[ 3 4⏎+ ] 2 *\" → {:?}", evaluate_rpn_from_reader(grouped));
+    
+    println!("\nOperand order for binary operators:");
+    let mut operands = Stack::new();
+    operands.push(10);
+    operands.push(3);
+    let applied = operands.apply_binary(|a, b| Ok(a - b));
+    println!("  push 10, push 3, apply_binary(a - b) → {:?}, top = {:?}",
+             applied, operands.peek());
+    
+    println!("\nUser-defined words (expanded before evaluation):");
+    let mut defs = HashMap::new();
+    for &(name, body) in &[("sq", "dup *"), ("cube", "dup dup * *")] {
+        if let Ok(tokens) = tokenize(body) {
+            println!("  def {:<4} = {}", name, body);
+            defs.insert(name.to_string(), tokens);
+        }
+    }
+    for expr in &["5 sq", "3 cube", "2 sq cube 1 -", "5 cbrt"] {
+        match evaluate_rpn_with_defs(expr, &defs) {
+            Ok(result) => println!("  {:<14} = {}", expr, result),
+            Err(e) => println!("  {:<14} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nFunctions with a fixed arity:");
+    let mut functions = HashMap::new();
+    functions.insert("clamp".to_string(), RpnFunction {
+        arity: 3,
+        apply: |args| Ok(args[0].max(args[1]).min(args[2])),
+    });
+    for expr in &["15 0 10 clamp", "-5 0 10 clamp 1 +", "5 10 clamp", "[ 3 4 + ] 0 5 clamp"] {
+        match evaluate_rpn_with_functions(expr, &functions) {
+            Ok(result) => println!("  {:<20} = {}", expr, result),
+            Err(e) => println!("  {:<20} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nGrouped sub-expressions (a stack of stacks):");
+    for expr in &["[ 3 4 + ] 2 *", "10 [ 2 [ 1 1 + ] * ] -", "[ 3 4 + 2 *", "3 ] 4 +"] {
+        match evaluate_rpn_with_depth(expr) {
+            Ok((result, depth)) => println!("  {:<24} = {:<4} peak depth {}", expr, result, depth),
+            Err(e) => println!("  {:<24} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nConstant folding (simplify once, evaluate later):");
+    for expr in &["2 3 +", "5 4 *", "2 3 + 4 *", "3 dup * neg", "[ 3 4 + ] 2 *", "1 +"] {
+        match simplify_rpn(expr) {
+            Ok(simplified) => println!("  {:<16} → {}", expr, simplified),
+            Err(e) => println!("  {:<16} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nValidating without evaluating (depth only):");
+    for expr in &["15 7 1 1 + - *", "3 +", "1 2 3 +", "[ 3 4 + ] 2 *", "[ 1 2 ] +", "", "[ ] 1"] {
+        match validate_rpn(expr) {
+            Ok(()) => println!("  {:?} → valid", expr),
+            Err(e) => println!("  {:?} → {}  (eval: {:?})", expr, e, evaluate_rpn(expr)),
+        }
+    }
+    
     println!("\nRPN (Reverse Polish Notation) uses a stack:");
     println!("  • Numbers pushed to stack");
     println!("  • Operators pop operands, push result");
     println!("  • Perfect stack application!");
 }
 
+fn demo_shunting_yard() {
+    println!("\n{}", "=".repeat(60));
+    println!("INFIX TO RPN: THE SHUNTING-YARD ALGORITHM");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nStandard precedence (* binds tighter than +):");
+    for expr in &["3 + 4 * 2", "(3 + 4) * 2", "10 - 4 - 3", "(1 + 2"] {
+        match infix_to_rpn(expr) {
+            Ok(rpn) => println!("  {:<12} → {:<10} = {:?}", expr, rpn, evaluate_rpn(&rpn)),
+            Err(e) => println!("  {:<12} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nMalformed input is caught during conversion:");
+    for expr in &["3 + * 4", "3 4", "2 (3)", "()", "3 -"] {
+        match infix_to_rpn(expr) {
+            Ok(rpn) => println!("  {:<12} → {}", expr, rpn),
+            Err(e) => println!("  {:<12} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nUnary minus (a '-' where an operand belongs):");
+    for expr in &["-3 + 4", "2 * -3", "-(2 + 3) * 4", "4 - -1", "-(-3)", "-", "- - 3"] {
+        match infix_to_rpn(expr) {
+            Ok(rpn) => println!("  {:<12} → {:<14} = {:?}", expr, rpn, evaluate_rpn(&rpn)),
+            Err(e) => println!("  {:<12} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nCustom precedence (+ binds tighter than *):");
+    let mut custom = default_precedence();
+    custom.insert('+', (3, Assoc::Left));
+    if let Ok(rpn) = infix_to_rpn_with("3 + 4 * 2", &custom) {
+        println!("  {:<12} → {:<10} = {:?}", "3 + 4 * 2", rpn, evaluate_rpn(&rpn));
+    }
+    
+    println!("\nRight associativity (a - b - c as a - (b - c)):");
+    custom.insert('-', (1, Assoc::Right));
+    if let Ok(rpn) = infix_to_rpn_with("10 - 4 - 3", &custom) {
+        println!("  {:<12} → {:<10} = {:?}", "10 - 4 - 3", rpn, evaluate_rpn(&rpn));
+    }
+    
+    println!("\nOperators wait on a stack until something weaker arrives");
+}
+
+fn demo_rpn_machine() {
+    println!("\n{}", "=".repeat(60));
+    println!("RPN MACHINE - BYTECODE INSTEAD OF TEXT");
+    println!("{}", "=".repeat(60));
+    
+    // Equivalent to "3 4 + dup *"
+    let program = [Op::Push(3), Op::Push(4), Op::Add, Op::Dup, Op::Mul];
+    let mut machine = RpnMachine::new();
+    println!("\n  Program: {:?}", program);
+    match machine.run(&program) {
+        Ok(result) => println!("  Result:  {}  ((3 + 4)²)", result),
+        Err(e) => println!("  Error:   {}", e),
+    }
+    
+    let swapped = [Op::Push(2), Op::Push(10), Op::Swap, Op::Sub];
+    let mut machine = RpnMachine::new();
+    println!("\n  Program: {:?}", swapped);
+    match machine.run(&swapped) {
+        Ok(result) => println!("  Result:  {}  (10 - 2)", result),
+        Err(e) => println!("  Error:   {}", e),
+    }
+    
+    // 5! with a loop: the stack holds [accumulator, counter]
+    let factorial = [
+        Op::Push(1),          // 0: acc = 1
+        Op::Push(5),          // 1: n = 5
+        Op::Dup,              // 2: loop: [acc, n, n]
+        Op::JumpIfZero(11),   // 3: n == 0 → done
+        Op::Swap,             // 4: [n, acc]
+        Op::Over,             // 5: [n, acc, n]
+        Op::Mul,              // 6: [n, acc * n]
+        Op::Swap,             // 7: [acc * n, n]
+        Op::Push(1),          // 8:
+        Op::Sub,              // 9: [acc * n, n - 1]
+        Op::Jump(2),          // 10: back to the loop test
+        Op::Add,              // 11: [acc, 0] → acc
+    ];
+    let mut machine = RpnMachine::new();
+    println!("\n  Factorial loop ({} ops with jumps):", factorial.len());
+    match machine.run(&factorial) {
+        Ok(result) => println!("  Result:  {}  (5!)", result),
+        Err(e) => println!("  Error:   {}", e),
+    }
+    
+    // (3 + 4)² + (3 + 4), computing 3 + 4 only once
+    let with_registers = [
+        Op::Push(3), Op::Push(4), Op::Add,
+        Op::Store(0),
+        Op::Load(0), Op::Load(0), Op::Mul,
+        Op::Load(0), Op::Add,
+    ];
+    println!("\n  Registers: store (3 + 4) in r0, reuse it three times");
+    match evaluate_program(&with_registers) {
+        Ok(result) => println!("  Result:  {}  (49 + 7)", result),
+        Err(e) => println!("  Error:   {}", e),
+    }
+    match evaluate_program(&[Op::Push(1), Op::Store(REGISTER_COUNT)]) {
+        Ok(result) => println!("  Result:  {}", result),
+        Err(e) => println!("  store {} → Error: {}", REGISTER_COUNT, e),
+    }
+    
+    println!("\n  Watching the stack op by op (peek_stack):");
+    let mut machine = RpnMachine::new();
+    for &op in &[Op::Push(5), Op::Push(2), Op::Dup, Op::Mul, Op::Sub] {
+        if machine.run(&[op]).is_ok() {
+            println!("  {:<10} → {:?}", format!("{:?}", op), machine.peek_stack());
+        }
+    }
+    
+    println!("\n  Undo, one op at a time:");
+    let mut machine = RpnMachine::new();
+    if machine.run(&[Op::Push(3), Op::Push(4), Op::Add]).is_ok() {
+        println!("  after 3 4 +: {:?}", machine.peek_stack());
+    }
+    machine.undo();
+    println!("  undo():      {:?}", machine.peek_stack());
+    machine.undo();
+    println!("  undo():      {:?}", machine.peek_stack());
+    
+    println!("\n  A failing run is rolled back as a whole:");
+    let mut machine = RpnMachine::new();
+    if machine.run(&[Op::Push(1)]).is_ok() {
+        println!("  after 1:     {:?}", machine.peek_stack());
+    }
+    println!("  run([Add]) → {:?}, stack still {:?}",
+             machine.run(&[Op::Add]), machine.peek_stack());
+    let mut fresh = RpnMachine::new();
+    let failing = [Op::Push(1), Op::Push(2), Op::Add, Op::Add];
+    println!("  run(1 2 + +) → {:?}, stack still {:?}",
+             fresh.run(&failing), fresh.peek_stack());
+    println!("  run(7 store r0) → {:?}, r0 still {}",
+             fresh.run(&[Op::Push(7), Op::Store(0)]), fresh.registers[0]);
+    let pushes = vec![Op::Push(1); UNDO_LIMIT];
+    if fresh.run(&pushes).is_ok() {
+        let levels = fresh.history.len();
+        let outcome = fresh.run(&[Op::Add, Op::Add, Op::Store(REGISTER_COUNT)]);
+        println!("  undo levels {} → {:?} → {}", levels, outcome, fresh.history.len());
+    }
+    
+    println!("\nParsing text and executing ops are now separate steps");
+    println!("Add a program counter and jumps → a tiny stack VM!");
+}
+
+fn demo_symbolic_derivative() {
+    println!("\n{}", "=".repeat(60));
+    println!("RPN → EXPRESSION TREE → SYMBOLIC DERIVATIVE");
+    println!("{}", "=".repeat(60));
+    
+    let cases = [
+        ("x x *", "2 x *"),                  // d/dx x²      = 2x
+        ("x 3 ^ 2 x * +", "3 x x * * 2 +"),  // d/dx x³ + 2x = 3x² + 2
+    ];
+    
+    for &(source, expected) in &cases {
+        let (expr, expected) = match (parse_expr(source), parse_expr(expected)) {
+            (Ok(expr), Ok(expected)) => (expr, expected),
+            _ => continue,
+        };
+        let derivative = differentiate(&expr, "x");
+        println!("\n  f(x)   = {}   (from \"{}\")", expr, source);
+        println!("  f'(x)  = {}", derivative);
+        for &x in &[1.0, 2.0, 3.0] {
+            println!("  f'({}) = {:<6} expected {}", x,
+                     derivative.eval("x", x), expected.eval("x", x));
+        }
+    }
+    
+    println!("\nDecimal literals (one point at most):");
+    for source in &["3.14 2 *", "0.5 x *", "3.1.4 2 *", "1e5"] {
+        match parse_expr(source) {
+            Ok(expr) => println!("  {:<10} → {:<10} = {} at x = 4", source, expr.to_string(),
+                                 expr.eval("x", 4.0)),
+            Err(e) => println!("  {:<10} → Error: {}", source, e),
+        }
+    }
+    
+    println!("\nThe parser is the RPN evaluator again - it just pushes");
+    println!("trees instead of numbers");
+}
+
+fn demo_bool_calculator() {
+    println!("\n{}", "=".repeat(60));
+    println!("BEYOND ARITHMETIC: POSTFIX BOOLEAN LOGIC");
+    println!("{}", "=".repeat(60));
+    
+    let expressions = vec![
+        "true false or",       // true
+        "true not",            // false
+        "true true xor",       // false
+        "true false and not",  // true
+        "true and",            // underflow
+    ];
+    
+    for expr in expressions {
+        match eval_bool_postfix(expr) {
+            Ok(result) => println!("  {} = {}", expr, result),
+            Err(e) => println!("  {} → Error: {}", expr, e),
+        }
+    }
+    
+    println!("\nSame stack, different values:");
+    println!("  • Operands are bools instead of numbers");
+    println!("  • not pops one, and/or/xor pop two");
+}
+
+fn demo_bracket_parser() {
+    println!("\n{}", "=".repeat(60));
+    println!("PRACTICAL EXAMPLE: BRACKET MATCHING");
+    println!("{}", "=".repeat(60));
+    
+    let inputs = vec![
+        "(a + {b * [c - d]})",
+        "([)]",
+        "(a + b]",
+        "((a)",
+    ];
+    
+    println!();
+    for input in inputs {
+        match BracketParser::first_error(input) {
+            None => println!("  {:<22} ✓ balanced", input),
+            Some(pos) => println!("  {:<22} ✗ error at byte {}", input, pos),
+        }
+    }
+    
+    println!("\nA position is more useful to an editor than true/false!");
+    
+    println!("\nMaximum nesting depth = peak stack size:");
+    for input in &["{[()]}", "{{}}", "{[(])}"] {
+        println!("  {:<22} {:?}", input, max_nesting_depth(input));
+    }
+    
+    println!("\nNamed tags work the same way (Stack<String>):");
+    for input in &["<a><b></b></a>", "<a></b>", "<a><br/></a>", "<a><b></a>"] {
+        println!("  {:<22} {}", input,
+                 if tags_balanced(input) { "✓ balanced" } else { "✗ mismatched" });
+    }
+}
+
+fn demo_csv_splitter() {
+    println!("\n{}", "=".repeat(60));
+    println!("PRACTICAL EXAMPLE: CSV WITH QUOTED FIELDS");
+    println!("{}", "=".repeat(60));
+    
+    println!();
+    for line in &["a,b,c", "\"a,b\",c", "\"say \"\"hi\"\"\",x", "a,,c"] {
+        println!("  {:<18} → {:?}", line, split_csv_line(line));
+    }
+    
+    println!("\nOnly top-level commas → ';' (parentheses protect theirs):");
+    for input in &["a,(b,c),d", "f(x,g(y,z)),w", "no,parens"] {
+        println!("  {:<16} → {}", input, replace_outside_brackets(input, ',', ';'));
+    }
+    
+    println!("\nQuotes and parentheses both open a context where commas");
+    println!("stop being separators");
+}
+
+fn demo_monotonic_stack() {
+    println!("\n{}", "=".repeat(60));
+    println!("PRACTICAL EXAMPLE: MONOTONIC STACK");
+    println!("{}", "=".repeat(60));
+    
+    let nums = [2, 1, 2, 4, 3];
+    println!("\nNext greater element:");
+    println!("  Input:  {:?}", nums);
+    println!("  Output: {:?}", next_greater(&nums));
+    
+    println!("\nLargest rectangle in a histogram:");
+    for heights in &[vec![2, 1, 5, 6, 2, 3], vec![]] {
+        println!("  {:?} → {}", heights, largest_rectangle(heights));
+    }
+    
+    println!("\nEach index is pushed once and popped once → O(n)");
+}
+
+fn demo_partition_search() {
+    println!("\n{}", "=".repeat(60));
+    println!("BACKTRACKING WITH AN EXPLICIT STACK");
+    println!("{}", "=".repeat(60));
+    
+    println!("\nSplit into two groups with equal sums?");
+    for nums in &[vec![1, 5, 11, 5], vec![1, 2, 3, 5], vec![3, 3, 3, 4, 5]] {
+        println!("  {:?} → {}", nums, can_partition_equal_sum(nums));
+    }
+    
+    println!("\nEach popped frame is one take-it-or-leave-it decision;");
+    println!("the stack plays the role of the call stack");
+}
+
+fn demo_bfs() {
+    println!("\n{}", "=".repeat(60));
+    println!("PRACTICAL EXAMPLE: BFS SHORTEST PATHS");
+    println!("{}", "=".repeat(60));
+    
+    //  0 - 1 - 3 - 4      5 (disconnected)
+    //   \     /
+    //    - 2 -
+    let adjacency = vec![
+        vec![1, 2],
+        vec![0, 3],
+        vec![0, 3],
+        vec![1, 2, 4],
+        vec![3],
+        vec![],
+    ];
+    
+    println!("\nHops from node 0:");
+    for (node, distance) in bfs_distances(&adjacency, 0).iter().enumerate() {
+        match *distance {
+            Some(d) => println!("  node {}: {}", node, d),
+            None => println!("  node {}: unreachable", node),
+        }
+    }
+    
+    // Square 0-1-2-3-0 plus a separate triangle 4-5-6-4
+    let even_cycle = vec![vec![1, 3], vec![0, 2], vec![1, 3], vec![2, 0]];
+    let mut with_triangle = even_cycle.clone();
+    with_triangle.extend(vec![vec![5, 6], vec![4, 6], vec![5, 4]]);
+    println!("\nBipartite check (two-coloring by BFS):");
+    println!("  4-cycle:              {}", is_bipartite(&even_cycle));
+    println!("  4-cycle + 3-cycle:    {}  (odd cycle in 2nd component)",
+             is_bipartite(&with_triangle));
+    
+    println!("\nFlood fill from (0, 0) with color 7:");
+    let mut grid = vec![
+        vec![1, 1, 0, 2],
+        vec![1, 0, 0, 2],
+        vec![1, 1, 0, 1],
+    ];
+    flood_fill(&mut grid, (0, 0), 7);
+    for row in &grid {
+        println!("  {:?}", row);
+    }
+    println!("  (the 1 at bottom-right isn't connected, so it stays)");
+    
+    //        1
+    //      /   \
+    //     2     3
+    //    / \     \
+    //   4   5     6
+    let tree = BinaryTree {
+        root: Some(Box::new(TreeNode::new(
+            1,
+            Some(TreeNode::new(2, Some(TreeNode::leaf(4)), Some(TreeNode::leaf(5)))),
+            Some(TreeNode::new(3, None, Some(TreeNode::leaf(6)))),
+        ))),
+    };
+    println!("\nLevel-order tree traversal:");
+    for (depth, level) in level_order(&tree).iter().enumerate() {
+        println!("  depth {}: {:?}", depth, level);
+    }
+    let pre_order: Vec<&i32> = tree.dfs_iter().collect();
+    println!("\nPre-order, lazily, with a stack instead of recursion:");
+    println!("  {:?}", pre_order);
+    
+    println!("\nThe queue visits nodes in order of distance:");
+    println!("  • All 1-hop nodes before any 2-hop node");
+    println!("  • First visit = shortest path (FIFO guarantees it)");
+}
+
 fn main() {
     println!("╔═══════════════════════════════════════════════════════════╗");
     println!("║    Rust Stack vs Queue - Type Safety & Collections       ║");
@@ -259,9 +2588,26 @@ fn main() {
     
     demo_basic_operations();
     demo_standard_collections();
+    demo_deque();
+    demo_queue_batches();
+    demo_two_stack_queue();
+    demo_delay_queue();
+    demo_mlfq_scheduler();
+    demo_message_buffer();
+    demo_rate_limiter();
+    demo_moving_average();
     demonstrate_ownership_with_collections();
     demonstrate_pattern_matching();
     demo_rpn_calculator();
+    demo_shunting_yard();
+    demo_rpn_machine();
+    demo_symbolic_derivative();
+    demo_bool_calculator();
+    demo_bracket_parser();
+    demo_csv_splitter();
+    demo_monotonic_stack();
+    demo_partition_search();
+    demo_bfs();
     
     println!("\n{}", "=".repeat(60));
     println!("KEY RUST INSIGHTS:");